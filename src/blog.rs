@@ -1,116 +1,1272 @@
+use std::collections::BTreeMap;
 use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::SystemTime;
 
 use chrono::prelude::*;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+mod action_log;
+mod assets;
+mod crypto;
+mod export;
+mod http;
+mod import;
+
+pub use action_log::{LoggedAction, log_action, log_file, log_tail};
+pub use assets::{copy_image, save_image};
+pub use export::{
+    ExportManifest, ManifestEntry, export_atom, export_html, export_html_with_progress,
+    export_post, export_rss, export_single_html, export_text, wrap_line,
+};
+pub use http::{PublishTarget, publish_to_url};
+pub use import::{import_dir, import_dir_with_progress, import_post};
+
 const POSTS_FILE_VAR_NAME: &str = "BLOG_POSTS_FILE";
+const SOLEILFOU_FORMAT: &str = "%Y:%m:%d:%H:%M:%S";
+/// The posts file format this version of the app reads and writes.
+const SCHEMA_VERSION: u32 = 1;
+const WORDS_PER_MINUTE: usize = 200;
+/// Punctuation that ends a sentence rather than the URL it comes after.
+const URL_TRAILING_PUNCTUATION: [char; 7] = ['.', ',', ';', ':', '!', '?', '\''];
+
+static BARE_URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"https?://[^\s<>\[\]()"`]+"#).unwrap());
 
-type BlogResult<T> = std::result::Result<T, BlogError>;
+pub type BlogResult<T> = std::result::Result<T, BlogError>;
+
+/// Told how many of the steps of a long job are done, out of how many, after each one; it
+/// breaks to cancel the rest.
+pub type Progress<'a> = &'a mut dyn FnMut(usize, usize) -> std::ops::ControlFlow<()>;
 
 #[derive(Debug, Error)]
 pub enum BlogError {
     #[error("Unable to Read Posts File")]
     UnableToReadPostsFile,
+    #[error("Posts file does not exist")]
+    FileNotFound,
     #[error("Posts file has unparsable JSON")]
     PostsFileUnParsable,
     #[error("Could not write to or save Posts file")]
     CouldNotWritePostsFile,
-    #[error("Could not find env var: {0}")]
-    EnvVarNotFound(String),
+    #[error("No post found at index {0}")]
+    PostNotFound(usize),
+    #[error("No footer link found at index {0}")]
+    FooterLinkNotFound(usize),
+    #[error("Unparsable timestamp: {0}")]
+    UnparsableTimestamp(String),
+    #[error("Please enter a title")]
+    EmptyTitle,
+    #[error("Please enter some content")]
+    EmptyContent,
+    #[error("Could not write export file: {0}")]
+    CouldNotWriteExport(String),
+    #[error("Invalid footer link URL: {0}")]
+    InvalidFooterLinkUrl(String),
+    #[error("Could not read import folder: {0}")]
+    UnableToReadImportDir(String),
+    #[error("Posts file was changed by another program - reload it before saving")]
+    FileChangedExternally,
+    #[error("Could not copy image: {0}")]
+    CouldNotCopyImage(String),
+    #[error("Could not save image: {0}")]
+    CouldNotSaveImage(String),
+    #[error("Posts file is schema version {0}, which is newer than this app understands")]
+    UnsupportedSchemaVersion(u32),
+    #[error("Invalid timestamp format: {0}")]
+    InvalidTimeFormat(String),
+    #[error("Posts file is encrypted - enter its passphrase to open it")]
+    PassphraseRequired,
+    #[error("Wrong passphrase, or the posts file is damaged")]
+    DecryptionFailed,
+    #[error("Post is {words} words long, over the limit of {max}")]
+    PostTooLong { words: usize, max: u32 },
+    #[error("Could not read HTML template: {0}")]
+    CouldNotReadTemplate(String),
+    #[error("No earlier version {0} of this post")]
+    VersionNotFound(usize),
+    #[error("HTML template is missing the {0} placeholder")]
+    MissingTemplatePlaceholder(&'static str),
+    #[error("Could not read post file: {0}")]
+    UnableToReadPostFile(String),
+    #[error("Post file has unparsable JSON")]
+    PostFileUnParsable,
+    #[error("Could not reach the publish URL: {0}")]
+    HttpRequestFailed(String),
+    #[error("The server answered {status}: {body}")]
+    HttpError { status: u16, body: String },
+    #[error("Cancelled")]
+    Cancelled,
+    #[error("Posts file path is not a file: {0}")]
+    NotAFile(String),
+    #[error("Could not write to the log: {0}")]
+    CouldNotWriteLog(String),
+    #[error("Could not read the log: {0}")]
+    UnableToReadLog(String),
 }
 
 #[derive(Debug)]
 pub struct Post {
     pub title: String,
     pub content: Vec<String>,
+    pub tags: Vec<String>,
+    pub published: bool,
+    /// When the post is dated, instead of now. Updating a post keeps its date unless set.
+    pub scheduled_time: Option<DateTime<Local>>,
+}
+
+/// Split `content` into the lines stored in the posts file. `\r\n` and a lone `\r` count
+/// as line breaks too. Blank lines are kept, except that a line break at the very end
+/// doesn't start another line, so `""` gives no lines at all.
+pub fn split_on_line_breaks(content: &str) -> Vec<String> {
+    content
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether an editor showing `title`, `content` and `tags` has nothing in it to lose: it has
+/// no tags and is blank, or holds just the `defaults` title and content a new post starts
+/// with, or that title alone, as it's left after publishing.
+pub fn is_unedited(title: &str, content: &str, tags: &str, defaults: (&str, &str)) -> bool {
+    if !tags.trim().is_empty() {
+        return false;
+    }
+    let (default_title, default_content) = defaults;
+    let is_empty = title.is_empty() && content.is_empty();
+    let is_placeholder =
+        title == default_title && (content.is_empty() || content == default_content);
+    is_empty || is_placeholder
+}
+
+/// Word count, character count and estimated reading time in minutes.
+pub fn content_stats(content: &str) -> (usize, usize, u32) {
+    let words = content.split_whitespace().count();
+    let characters = content.chars().count();
+    let minutes = words.div_ceil(WORDS_PER_MINUTE) as u32;
+    (words, characters, minutes)
+}
+
+/// Replace every occurrence of `find` in `haystack` with `replace`. An empty `find` leaves
+/// `haystack` unchanged.
+pub fn replace_all(haystack: &str, find: &str, replace: &str, case_sensitive: bool) -> String {
+    if find.is_empty() {
+        return haystack.to_owned();
+    }
+    if case_sensitive {
+        return haystack.replace(find, replace);
+    }
+    let mut result = String::with_capacity(haystack.len());
+    let mut rest = haystack;
+    while let Some(c) = rest.chars().next() {
+        match match_len_ignoring_case(rest, find) {
+            Some(len) => {
+                result.push_str(replace);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    result
+}
+
+/// The byte length of the match if `text` starts with `find`, ignoring case.
+fn match_len_ignoring_case(text: &str, find: &str) -> Option<usize> {
+    let mut text_chars = text.char_indices();
+    for f in find.chars() {
+        let (_, t) = text_chars.next()?;
+        if !t.to_lowercase().eq(f.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(text_chars.next().map_or(text.len(), |(index, _)| index))
+}
+
+/// A line of [`diff_content`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineChange {
+    Unchanged(String),
+    Added(String),
+    Removed(String),
+}
+
+/// What editing a post's `stored` content into `edited` changes, line by line, in order.
+pub fn diff_content(stored: &[String], edited: &str) -> Vec<LineChange> {
+    let edited = split_on_line_breaks(edited);
+    let old: Vec<&str> = stored.iter().map(String::as_str).collect();
+    let new: Vec<&str> = edited.iter().map(String::as_str).collect();
+    similar::TextDiff::from_slices(&old, &new)
+        .iter_all_changes()
+        .map(|change| {
+            let line = change.value().to_string();
+            match change.tag() {
+                similar::ChangeTag::Equal => LineChange::Unchanged(line),
+                similar::ChangeTag::Insert => LineChange::Added(line),
+                similar::ChangeTag::Delete => LineChange::Removed(line),
+            }
+        })
+        .collect()
+}
+
+/// Turn the bare `http(s)://` URLs in a line of Markdown into `[url](url)` links. URLs that
+/// are already part of a link (`[text](url)`, `[url]`, `<url>`) or in inline code are left
+/// alone, and punctuation straight after a URL stays outside the link.
+pub fn autolink(line: &str) -> String {
+    let mut linked = String::with_capacity(line.len());
+    let mut copied = 0;
+    for found in BARE_URL.find_iter(line) {
+        let before = &line[..found.start()];
+        let already_linked = before.ends_with("](") || before.ends_with(['[', '<']);
+        let in_code = before.matches('`').count() % 2 == 1;
+        let url = found.as_str().trim_end_matches(URL_TRAILING_PUNCTUATION);
+        if already_linked || in_code || url.ends_with("://") {
+            continue;
+        }
+        linked.push_str(&line[copied..found.start()]);
+        linked.push_str(&format!("[{}]({})", url, url));
+        copied = found.start() + url.len();
+    }
+    linked.push_str(&line[copied..]);
+    linked
+}
+
+/// `time` as a `woa_time` in `format`, which [`parse_time`] reads back.
+pub fn format_time(time: DateTime<Local>, format: &str) -> String {
+    time.format(format).to_string()
+}
+
+fn parse_naive(s: &str, format: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, format)
+        .ok()
+        .or_else(|| Some(NaiveDate::parse_from_str(s, format).ok()?.into()))
 }
 
-fn time_to_soleilfou(time: DateTime<Local>) -> String {
-    time.format("%Y:%m:%d:%H:%M:%S").to_string()
+/// Parse a `woa_time` written with `format`, falling back to the soleilfou format that
+/// posts from before the format was changed are in. Date-only formats give midnight.
+pub fn parse_time(s: &str, format: &str) -> BlogResult<DateTime<Local>> {
+    parse_naive(s, format)
+        .or_else(|| parse_naive(s, SOLEILFOU_FORMAT))
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .ok_or_else(|| BlogError::UnparsableTimestamp(s.to_string()))
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct PostForJson {
-    woa_time: String,
-    title: String,
-    content: Vec<String>,
+pub fn soleilfou_to_time(s: &str) -> BlogResult<DateTime<Local>> {
+    parse_time(s, SOLEILFOU_FORMAT)
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct FooterLink {
-    label: String,
-    url: String,
+/// How long before `now` `then` was, e.g. "2 hours ago", or "in 3 days" if it's after it.
+/// Anything within ten seconds is "just now".
+pub fn relative_time(then: DateTime<Local>, now: DateTime<Local>) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const WEEK: i64 = 7 * DAY;
+
+    let seconds = now.signed_duration_since(then).num_seconds();
+    let (amount, unit) = match seconds.abs() {
+        0..10 => return "just now".to_string(),
+        s @ 10..MINUTE => (s, "second"),
+        s @ MINUTE..HOUR => (s / MINUTE, "minute"),
+        s @ HOUR..DAY => (s / HOUR, "hour"),
+        s @ DAY..WEEK => (s / DAY, "day"),
+        s => (s / WEEK, "week"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    if seconds < 0 {
+        format!("in {} {}{}", amount, unit, plural)
+    } else {
+        format!("{} {}{} ago", amount, unit, plural)
+    }
+}
+
+/// URL-friendly version of a title: "Hello, World!" -> "hello-world".
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if (c.is_whitespace() || c == '-') && !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "untitled".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Fail with [`BlogError::NotAFile`] if `filename` is a directory, a symlink loop or anything
+/// else that can't be read or written as a file. It's fine for it not to exist yet.
+fn ensure_file_or_missing(filename: &Path) -> BlogResult<()> {
+    let not_a_file =
+        |reason: &str| BlogError::NotAFile(format!("{} {}", filename.display(), reason));
+    match std::fs::metadata(filename) {
+        Ok(metadata) if metadata.is_dir() => Err(not_a_file("is a folder")),
+        Ok(metadata) if !metadata.is_file() => Err(not_a_file("is not a regular file")),
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        // The link itself is there, but following it fails, as in a loop:
+        Err(_) if std::fs::symlink_metadata(filename).is_ok() => {
+            Err(not_a_file("is a symlink that doesn't lead to a file"))
+        }
+        Err(_) => Err(BlogError::UnableToReadPostsFile),
+    }
+}
+
+/// `posts.json` -> `posts.json.<extension>`
+/// The `N` of every `path.N` next to `path`.
+fn backup_numbers(path: &Path) -> Vec<usize> {
+    let (Some(name), Some(dir)) = (path.file_name(), path.parent()) else {
+        return vec![];
+    };
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    let prefix = format!("{}.", name.to_string_lossy());
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            file_name.to_str()?.strip_prefix(&prefix)?.parse().ok()
+        })
+        .collect()
+}
+
+fn with_extra_extension(filename: &Path, extension: &str) -> PathBuf {
+    let mut name = filename.as_os_str().to_owned();
+    name.push(".");
+    name.push(extension);
+    PathBuf::from(name)
+}
+
+/// Copy `path` to `path.1`, first moving each `path.N` up to `path.N+1` and deleting the
+/// ones past `keep`, so `.1` is always the newest backup. Does nothing to the backups but
+/// prune them if `path` doesn't exist.
+pub fn rotate_backups(path: &Path, keep: usize) -> BlogResult<()> {
+    let backup = |number: usize| with_extra_extension(path, &number.to_string());
+    // Including any left from when more were kept, even past a gap in the numbers:
+    for number in backup_numbers(path) {
+        if number > keep {
+            std::fs::remove_file(backup(number)).map_err(|_| BlogError::CouldNotWritePostsFile)?;
+        }
+    }
+    if keep == 0 || !path.exists() {
+        return Ok(());
+    }
+    for number in (1..keep).rev() {
+        if backup(number).exists() {
+            std::fs::rename(backup(number), backup(number + 1))
+                .map_err(|_| BlogError::CouldNotWritePostsFile)?;
+        }
+    }
+    std::fs::copy(path, backup(1)).map_err(|_| BlogError::CouldNotWritePostsFile)?;
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PostForJson {
+    pub woa_time: String,
+    pub title: String,
+    pub content: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Older files have no slug; one is derived from the title when they are migrated.
+    #[serde(default)]
+    pub slug: String,
+    /// Unpublished posts are kept in the file but left out of exports.
+    #[serde(default = "default_published")]
+    pub published: bool,
+    /// Files beside the posts file that the content references, copied along on export.
+    #[serde(default)]
+    pub assets: Vec<String>,
+    /// Earlier versions of the post, oldest first, each with `woa_time` set to when it was
+    /// replaced. Left out of the file while there are none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<PostForJson>,
+}
+
+impl PostForJson {
+    /// Keep a copy of the post as it is now in its history, dropping the oldest versions
+    /// past `max_history`.
+    fn push_version(&mut self, time_format: &str, max_history: usize) {
+        let mut version = self.clone();
+        version.history.clear();
+        version.woa_time = format_time(Local::now(), time_format);
+        self.history.push(version);
+        let excess = self.history.len().saturating_sub(max_history);
+        self.history.drain(..excess);
+    }
+
+    /// Split any content line with a line break left inside it, e.g. a stray `\r` from a
+    /// file written on Windows, the way [`split_on_line_breaks`] would have. The title only
+    /// loses whitespace around it. Earlier versions get the same treatment.
+    pub fn normalize(&mut self) {
+        self.title = self.title.trim().to_string();
+        self.content = self
+            .content
+            .iter()
+            .flat_map(|line| {
+                if !line.contains(['\r', '\n']) {
+                    return vec![line.clone()];
+                }
+                let lines = split_on_line_breaks(line);
+                // A line that was only a line break is still a blank line:
+                if lines.is_empty() {
+                    vec![String::new()]
+                } else {
+                    lines
+                }
+            })
+            .collect();
+        for version in &mut self.history {
+            version.normalize();
+        }
+    }
+
+    /// The post as a Markdown document, with the title as its `# heading`.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("# {}\n", self.title);
+        if !self.content.is_empty() {
+            markdown.push('\n');
+        }
+        for line in &self.content {
+            markdown.push_str(line);
+            markdown.push('\n');
+        }
+        markdown
+    }
+
+    /// The post on its own, as written by [`export_post`].
+    pub fn to_json_string(&self) -> BlogResult<String> {
+        serde_json::to_string_pretty(self).map_err(|_| BlogError::CouldNotWritePostsFile)
+    }
+
+    pub fn from_json_string(json_string: &str) -> BlogResult<PostForJson> {
+        let mut post: PostForJson =
+            serde_json::from_str(json_string).map_err(|_| BlogError::PostFileUnParsable)?;
+        post.normalize();
+        if post.slug.is_empty() {
+            post.slug = slugify(&post.title);
+        }
+        Ok(post)
+    }
+
+    /// Whether an editor showing `title`, `content`, the comma-separated `tags` and
+    /// `published` has changes to the post that haven't been saved.
+    pub fn is_edited(&self, title: &str, content: &str, tags: &str, published: bool) -> bool {
+        self.title != title
+            || self.content.join("\n") != content
+            || self.tags.join(", ") != tags
+            || self.published != published
+    }
+
+    /// The content on one line, cut to at most `max` characters with an ellipsis after it
+    /// if it was longer.
+    pub fn excerpt(&self, max: usize) -> String {
+        let text = self
+            .content
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.chars().count() <= max {
+            return text;
+        }
+        let cut: String = text.chars().take(max).collect();
+        format!("{}…", cut.trim_end())
+    }
+}
+
+fn default_published() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FooterLink {
+    pub label: String,
+    pub url: String,
+}
+
+impl FooterLink {
+    pub fn validate(&self) -> BlogResult<()> {
+        if self.url.trim().is_empty() {
+            return Err(BlogError::InvalidFooterLinkUrl("URL is empty".to_string()));
+        }
+        url::Url::parse(&self.url)
+            .map(|_| ())
+            .map_err(|e| BlogError::InvalidFooterLinkUrl(format!("{}: {}", self.url, e)))
+    }
+
+    /// Why the link would be broken on an exported site, if it isn't an absolute
+    /// `http(s)` URL. Unlike [`Self::validate`] this doesn't stop it being saved.
+    pub fn url_warning(&self) -> Option<String> {
+        if let Err(e) = self.validate() {
+            return Some(e.to_string());
+        }
+        let url = url::Url::parse(&self.url).ok()?;
+        (!matches!(url.scheme(), "http" | "https"))
+            .then(|| format!("{} isn't an http(s) URL", self.url))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Page {
+    pub title: String,
+    pub css: String,
+    /// The `strftime` format new posts get their `woa_time` in.
+    #[serde(default = "default_time_format")]
+    pub time_format: String,
+    /// How many earlier versions of each post to keep when it's updated.
+    #[serde(default = "default_max_history")]
+    pub max_history: usize,
+    /// How many numbered backups of the posts file to keep when it's saved.
+    #[serde(default = "default_max_backups")]
+    pub max_backups: usize,
+    /// Posts longer than this many words are rejected, for microblog-style limits.
+    #[serde(default)]
+    pub max_words: Option<u32>,
+    /// An HTML file to export pages with instead of the built-in layout, relative to the
+    /// posts file unless it's absolute.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Where the exported site is hosted, which the RSS feed links to.
+    #[serde(default)]
+    pub site_url: Option<String>,
+}
+
+fn default_time_format() -> String {
+    SOLEILFOU_FORMAT.to_string()
+}
+
+fn default_max_history() -> usize {
+    10
+}
+
+fn default_max_backups() -> usize {
+    5
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            css: String::new(),
+            time_format: default_time_format(),
+            max_history: default_max_history(),
+            max_backups: default_max_backups(),
+            max_words: None,
+            template: None,
+            site_url: None,
+        }
+    }
+}
+
+impl Page {
+    /// Check `time_format` by formatting the current time with it and reading that back.
+    pub fn validate(&self) -> BlogResult<()> {
+        use std::fmt::Write;
+
+        let now = Local::now();
+        let mut formatted = String::new();
+        write!(formatted, "{}", now.format(&self.time_format))
+            .map_err(|_| BlogError::InvalidTimeFormat(self.time_format.clone()))?;
+        if formatted.is_empty() || parse_naive(&formatted, &self.time_format).is_none() {
+            return Err(BlogError::InvalidTimeFormat(format!(
+                "{} can't be read back as a date",
+                self.time_format
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The month key for posts whose `woa_time` doesn't parse.
+pub const UNKNOWN_MONTH: &str = "unknown";
+
+/// Counts over all posts, for an overview of the blog.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BlogStats {
+    pub total_posts: usize,
+    /// Keyed by `YYYY-MM`, or [`UNKNOWN_MONTH`].
+    pub posts_per_month: BTreeMap<String, usize>,
+    /// Most used first, ties in alphabetical order.
+    pub tag_counts: Vec<(String, usize)>,
+}
+
+/// Something that will look wrong on the exported site, without stopping it being exported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationWarning {
+    /// Which footer link it's about.
+    pub index: usize,
+    pub message: String,
+}
+
+/// What publishing a post would do, worked out without writing anything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishPlan {
+    /// The posts file doesn't exist yet and would be created.
+    pub creates_file: bool,
+    pub woa_time: String,
+    pub slug: String,
+    /// How many posts there would be afterwards.
+    pub post_count: usize,
+}
+
+/// Which end lists and exports start from. Posts are stored newest first either way.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub enum PostOrder {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+}
+
+impl PostOrder {
+    /// `iter`, which goes newest first like the stored posts, in this order.
+    pub fn apply<I: DoubleEndedIterator>(self, iter: I) -> InOrder<I> {
+        InOrder { iter, order: self }
+    }
+}
+
+/// An iterator over posts in a [`PostOrder`], made by [`PostOrder::apply`].
+pub struct InOrder<I> {
+    iter: I,
+    order: PostOrder,
+}
+
+impl<I: DoubleEndedIterator> Iterator for InOrder<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.order {
+            PostOrder::NewestFirst => self.iter.next(),
+            PostOrder::OldestFirst => self.iter.next_back(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct Page {
-    title: String,
-    css: String,
+impl<I: DoubleEndedIterator> DoubleEndedIterator for InOrder<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        match self.order {
+            PostOrder::NewestFirst => self.iter.next_back(),
+            PostOrder::OldestFirst => self.iter.next(),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct BlogPostsForJson {
+impl<I: DoubleEndedIterator + ExactSizeIterator> ExactSizeIterator for InOrder<I> {}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct BlogPostsForJson {
+    /// Files from before versioning have none and are version 0.
+    #[serde(default)]
+    schema_version: u32,
     page: Page,
     footer_links: Vec<FooterLink>,
     posts: Vec<PostForJson>,
+    /// Modification time of the posts file when this was loaded from or saved to it.
+    #[serde(skip)]
+    modified: Option<SystemTime>,
+    /// Set when the posts file is encrypted, to encrypt it again on save.
+    #[serde(skip)]
+    passphrase: Option<String>,
+    /// Save the posts file as a single line instead of pretty-printing it.
+    #[serde(skip)]
+    compact_json: bool,
+    /// The order exports list the posts in, which isn't saved to the posts file.
+    #[serde(skip)]
+    order: PostOrder,
 }
 
 impl BlogPostsForJson {
+    pub fn posts(&self) -> &[PostForJson] {
+        &self.posts
+    }
+
+    /// The posts in [`Self::order`], leaving them stored newest first.
+    pub fn ordered_posts(&self) -> InOrder<std::slice::Iter<'_, PostForJson>> {
+        self.order.apply(self.posts.iter())
+    }
+
+    pub fn order(&self) -> PostOrder {
+        self.order
+    }
+
+    pub fn set_order(&mut self, order: PostOrder) {
+        self.order = order;
+    }
+
+    pub fn page(&self) -> &Page {
+        &self.page
+    }
+
+    /// The footer links that won't work on an exported site.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        self.footer_links
+            .iter()
+            .enumerate()
+            .filter_map(|(index, link)| {
+                let message = link.url_warning()?;
+                Some(ValidationWarning { index, message })
+            })
+            .collect()
+    }
+
+    pub fn footer_links(&self) -> &[FooterLink] {
+        &self.footer_links
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.passphrase.is_some()
+    }
+
+    /// Indices of the posts whose title or content contains `query`, ignoring case.
+    pub fn search(&self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        self.posts
+            .iter()
+            .enumerate()
+            .filter(|(_, post)| {
+                post.title.to_lowercase().contains(&query)
+                    || post.content.join("\n").to_lowercase().contains(&query)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The `page`th (from 0) run of `size` posts; empty past the last one.
+    pub fn posts_page(&self, page: usize, size: usize) -> &[PostForJson] {
+        let start = page.saturating_mul(size).min(self.posts.len());
+        let end = start.saturating_add(size).min(self.posts.len());
+        &self.posts[start..end]
+    }
+
+    /// Whether a post already has `title`, ignoring case and surrounding whitespace.
+    pub fn title_exists(&self, title: &str) -> bool {
+        let title = title.trim().to_lowercase();
+        self.posts
+            .iter()
+            .any(|post| post.title.trim().to_lowercase() == title)
+    }
+
+    /// When `post` was published, read with this blog's timestamp format.
+    pub fn post_time(&self, post: &PostForJson) -> BlogResult<DateTime<Local>> {
+        parse_time(&post.woa_time, &self.page.time_format)
+    }
+
+    /// The newest post from on or before `date`, or none if every post is newer. Relies on
+    /// the posts being sorted newest first, as they are once loaded; posts whose `woa_time`
+    /// doesn't parse are skipped.
+    pub fn index_for_date(&self, date: NaiveDate) -> Option<usize> {
+        self.posts.iter().position(|post| {
+            self.post_time(post)
+                .is_ok_and(|time| time.date_naive() <= date)
+        })
+    }
+
+    pub fn stats(&self) -> BlogStats {
+        let mut posts_per_month: BTreeMap<String, usize> = BTreeMap::new();
+        let mut tags: BTreeMap<&str, usize> = BTreeMap::new();
+        for post in &self.posts {
+            let month = self
+                .post_time(post)
+                .map(|time| time.format("%Y-%m").to_string())
+                .unwrap_or_else(|_| UNKNOWN_MONTH.to_string());
+            *posts_per_month.entry(month).or_default() += 1;
+            for tag in &post.tags {
+                *tags.entry(tag).or_default() += 1;
+            }
+        }
+        let mut tag_counts: Vec<(String, usize)> = tags
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect();
+        // Stable, so equal counts stay in the map's alphabetical order:
+        tag_counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        BlogStats {
+            total_posts: self.posts.len(),
+            posts_per_month,
+            tag_counts,
+        }
+    }
+
+    /// `slug`, or if a post already has it, `slug` with the first free `-2`, `-3`, … after it.
+    fn unique_slug(&self, slug: &str) -> String {
+        let taken = |candidate: &str| self.posts.iter().any(|post| post.slug == candidate);
+        if !taken(slug) {
+            return slug.to_string();
+        }
+        (2..)
+            .map(|n| format!("{}-{}", slug, n))
+            .find(|candidate| !taken(candidate))
+            .unwrap()
+    }
+
+    /// Newest first, with posts whose `woa_time` doesn't parse at the end.
+    pub fn sort_by_time(&mut self) {
+        let format = self.page.time_format.clone();
+        self.posts
+            .sort_by_cached_key(|post| std::cmp::Reverse(parse_time(&post.woa_time, &format).ok()));
+    }
+
     fn from_json_string(json_string: &str) -> BlogResult<BlogPostsForJson> {
-        serde_json::from_str(json_string).map_err(|_| BlogError::PostsFileUnParsable)
+        let mut blog_posts: BlogPostsForJson =
+            serde_json::from_str(json_string).map_err(|_| BlogError::PostsFileUnParsable)?;
+        blog_posts.migrate()?;
+        for post in &mut blog_posts.posts {
+            post.normalize();
+        }
+        Ok(blog_posts)
+    }
+
+    /// Upgrade a file written by an older version of the app to `SCHEMA_VERSION`.
+    /// Fields added along the way get their defaults from serde; this fills in the rest.
+    fn migrate(&mut self) -> BlogResult<()> {
+        if self.schema_version > SCHEMA_VERSION {
+            return Err(BlogError::UnsupportedSchemaVersion(self.schema_version));
+        }
+        if self.schema_version < 1 {
+            // Older files have no slug:
+            for post in self.posts.iter_mut().filter(|post| post.slug.is_empty()) {
+                post.slug = slugify(&post.title);
+            }
+        }
+        self.schema_version = SCHEMA_VERSION;
+        Ok(())
     }
 
     fn to_json_string(&self) -> BlogResult<String> {
         serde_json::to_string(self).map_err(|_| BlogError::CouldNotWritePostsFile)
     }
 
-    fn save_to_file(&self, filename: &str) -> BlogResult<()> {
-        let json_string = self.to_json_string()?;
-        std::fs::write(filename, json_string).map_err(|_| BlogError::CouldNotWritePostsFile)
+    /// Like the compact form, but indented with a line per field so the posts file diffs
+    /// well under version control.
+    pub fn to_json_string_pretty(&self) -> BlogResult<String> {
+        serde_json::to_string_pretty(self).map_err(|_| BlogError::CouldNotWritePostsFile)
+    }
+
+    /// Whether [`Self::save_to_file`] pretty-prints the file, which it does unless told not to.
+    pub fn set_pretty_json(&mut self, pretty: bool) {
+        self.compact_json = !pretty;
+    }
+
+    /// Write to `filename`, keeping the previous file as the newest of the page's
+    /// `max_backups` numbered backups. Unlike [`save`] this
+    /// doesn't check whether the file changed since it was loaded.
+    pub fn save_to_file(&self, filename: &Path) -> BlogResult<()> {
+        ensure_file_or_missing(filename)?;
+        let json_string = if self.compact_json {
+            self.to_json_string()?
+        } else {
+            self.to_json_string_pretty()?
+        };
+        let contents = match &self.passphrase {
+            Some(passphrase) => crypto::encrypt(json_string.as_bytes(), passphrase)?,
+            None => json_string.into_bytes(),
+        };
+        rotate_backups(filename, self.page.max_backups)?;
+        // Write next to the original and rename over it, so a crash mid-write can't truncate it:
+        let temp_filename = with_extra_extension(filename, "tmp");
+        std::fs::write(&temp_filename, contents).map_err(|_| BlogError::CouldNotWritePostsFile)?;
+        std::fs::rename(&temp_filename, filename).map_err(|_| {
+            let _ = std::fs::remove_file(&temp_filename);
+            BlogError::CouldNotWritePostsFile
+        })
+    }
+
+    /// Read `filename`, failing with [`BlogError::FileNotFound`] if it doesn't exist,
+    /// [`BlogError::NotAFile`] if it's a folder and [`BlogError::PassphraseRequired`] if it's
+    /// encrypted. Posts are sorted newest first.
+    pub fn from_file(filename: &Path) -> BlogResult<BlogPostsForJson> {
+        BlogPostsForJson::read_file(filename, None)
     }
 
-    fn from_file(filename: &str) -> BlogResult<BlogPostsForJson> {
-        let file_contents =
-            std::fs::read_to_string(filename).map_err(|_| BlogError::UnableToReadPostsFile)?;
-        BlogPostsForJson::from_json_string(&file_contents)
+    /// Like [`Self::from_file`], decrypting the file with `passphrase` if it's encrypted.
+    pub fn from_encrypted_file(filename: &Path, passphrase: &str) -> BlogResult<BlogPostsForJson> {
+        BlogPostsForJson::read_file(filename, Some(passphrase))
     }
 
-    fn add_post(&mut self, post: Post) {
-        self.posts.insert(0, post.for_json());
+    fn read_file(filename: &Path, passphrase: Option<&str>) -> BlogResult<BlogPostsForJson> {
+        ensure_file_or_missing(filename)?;
+        let mut file_contents = std::fs::read(filename).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => BlogError::FileNotFound,
+            _ => BlogError::UnableToReadPostsFile,
+        })?;
+        let encrypted = crypto::is_encrypted(&file_contents);
+        if encrypted {
+            let passphrase = passphrase.ok_or(BlogError::PassphraseRequired)?;
+            file_contents = crypto::decrypt(&file_contents, passphrase)?;
+        }
+        let json_string =
+            String::from_utf8(file_contents).map_err(|_| BlogError::PostsFileUnParsable)?;
+        let mut blog_posts = BlogPostsForJson::from_json_string(&json_string)?;
+        blog_posts.modified = modified_time(filename);
+        if encrypted {
+            blog_posts.passphrase = passphrase.map(str::to_string);
+        }
+        // Sort on every load so post indices agree between the app and the file it saves.
+        blog_posts.sort_by_time();
+        Ok(blog_posts)
+    }
+
+    /// What [`publish`] would do with `post`, failing the same way if it's invalid.
+    pub fn publish_plan(&self, post: &Post, creates_file: bool) -> BlogResult<PublishPlan> {
+        post.validate(&self.page)?;
+        let post_for_json = post.for_json(&self.page.time_format);
+        Ok(PublishPlan {
+            creates_file,
+            woa_time: post_for_json.woa_time,
+            slug: post_for_json.slug,
+            post_count: self.posts.len() + 1,
+        })
+    }
+
+    pub fn add_post(&mut self, post: Post) {
+        self.posts.insert(0, post.for_json(&self.page.time_format));
+        // A back- or future-dated post doesn't belong at the top:
+        self.sort_by_time();
+    }
+
+    /// Replace the post at `index` with `post`, keeping its slug and, unless `post` has a
+    /// `scheduled_time`, its date.
+    pub fn update_post(&mut self, index: usize, post: Post) -> BlogResult<()> {
+        let existing = self
+            .posts
+            .get_mut(index)
+            .ok_or(BlogError::PostNotFound(index))?;
+        let changed = existing.title != post.title
+            || existing.content != post.content
+            || existing.tags != post.tags;
+        if changed {
+            existing.push_version(&self.page.time_format, self.page.max_history);
+        }
+        existing.assets = assets::referenced_assets(&post.content);
+        existing.title = post.title;
+        existing.content = post.content;
+        existing.tags = post.tags;
+        existing.published = post.published;
+        if let Some(time) = post.scheduled_time {
+            existing.woa_time = format_time(time, &self.page.time_format);
+            self.sort_by_time();
+        }
+        Ok(())
+    }
+
+    /// Make `version` from the history of the post at `index` current again, keeping the
+    /// post's date and moving what was current into the history.
+    fn restore_version(&mut self, index: usize, version: usize) -> BlogResult<()> {
+        let post = self
+            .posts
+            .get_mut(index)
+            .ok_or(BlogError::PostNotFound(index))?;
+        if version >= post.history.len() {
+            return Err(BlogError::VersionNotFound(version));
+        }
+        let restored = post.history.remove(version);
+        // Pushing may drop the oldest version, so restore after removing it rather than before:
+        post.push_version(&self.page.time_format, self.page.max_history.max(1));
+        post.title = restored.title;
+        post.content = restored.content;
+        post.tags = restored.tags;
+        post.slug = restored.slug;
+        post.published = restored.published;
+        post.assets = restored.assets;
+        Ok(())
+    }
+
+    fn set_published(&mut self, index: usize, published: bool) -> BlogResult<()> {
+        self.posts
+            .get_mut(index)
+            .ok_or(BlogError::PostNotFound(index))?
+            .published = published;
+        Ok(())
     }
+
+    fn set_footer_links(&mut self, footer_links: Vec<FooterLink>) -> BlogResult<()> {
+        for link in &footer_links {
+            link.validate()?;
+        }
+        self.footer_links = footer_links;
+        Ok(())
+    }
+
+    fn set_page(&mut self, page: Page) -> BlogResult<()> {
+        page.validate()?;
+        self.page = page;
+        Ok(())
+    }
+
+    pub fn remove_post(&mut self, index: usize) -> BlogResult<()> {
+        if index >= self.posts.len() {
+            return Err(BlogError::PostNotFound(index));
+        }
+        self.posts.remove(index);
+        Ok(())
+    }
+
+    /// Move the post at `from` to `to`, shifting the ones in between. The next change that
+    /// sorts the posts by time undoes this.
+    pub fn move_post(&mut self, from: usize, to: usize) -> BlogResult<()> {
+        move_item(&mut self.posts, from, to).map_err(BlogError::PostNotFound)
+    }
+
+    /// Move the footer link at `from` to `to`, shifting the ones in between.
+    pub fn move_footer_link(&mut self, from: usize, to: usize) -> BlogResult<()> {
+        move_item(&mut self.footer_links, from, to).map_err(BlogError::FooterLinkNotFound)
+    }
+}
+
+/// Move `items[from]` to `to`, or fail with whichever index is out of bounds.
+fn move_item<T>(items: &mut [T], from: usize, to: usize) -> Result<(), usize> {
+    if let Some(index) = [from, to].into_iter().find(|&index| index >= items.len()) {
+        return Err(index);
+    }
+    if from < to {
+        items[from..=to].rotate_left(1);
+    } else {
+        items[to..=from].rotate_right(1);
+    }
+    Ok(())
 }
 
 impl Post {
-    fn for_json(&self) -> PostForJson {
-        let sf_time = time_to_soleilfou(Local::now());
+    /// Check the post can be published on `page`.
+    pub fn validate(&self, page: &Page) -> BlogResult<()> {
+        if self.title.trim().is_empty() {
+            return Err(BlogError::EmptyTitle);
+        }
+        if self.content.iter().all(|line| line.trim().is_empty()) {
+            return Err(BlogError::EmptyContent);
+        }
+        let words = self.word_count();
+        if let Some(max) = page.max_words
+            && words > max as usize
+        {
+            return Err(BlogError::PostTooLong { words, max });
+        }
+        Ok(())
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.content
+            .iter()
+            .map(|line| line.split_whitespace().count())
+            .sum()
+    }
+
+    /// How many bytes this post takes up once serialized into the posts file.
+    pub fn json_size(&self) -> usize {
+        serde_json::to_string(&self.for_json(SOLEILFOU_FORMAT))
+            .map(|json| json.len())
+            .unwrap_or(0)
+    }
+
+    fn for_json(&self, time_format: &str) -> PostForJson {
+        let time = self.scheduled_time.unwrap_or_else(Local::now);
         PostForJson {
-            woa_time: sf_time,
+            woa_time: format_time(time, time_format),
             title: self.title.clone(),
             content: self.content.clone(),
+            tags: self.tags.clone(),
+            slug: slugify(&self.title),
+            published: self.published,
+            assets: assets::referenced_assets(&self.content),
+            history: vec![],
         }
     }
 }
 
-pub fn publish(post: Post) -> BlogResult<bool> {
-    let error_fmt = format!(
-        "No blog posts file specified - please set the env var:'{}'",
-        POSTS_FILE_VAR_NAME
-    );
-    let filename = env::var(POSTS_FILE_VAR_NAME)
-        .map_err(|e| BlogError::EnvVarNotFound(format!("{}:{}", error_fmt, e.to_string())))?;
-    let mut blog_posts =
-        BlogPostsForJson::from_file(&filename).unwrap_or_else(|_| BlogPostsForJson {
-            posts: vec![],
-            footer_links: vec![],
-            page: Page {
-                title: "".to_string(),
-                css: "".to_string(),
-            },
-        });
-    blog_posts.add_post(post);
-    blog_posts
-        .save_to_file(&filename)
-        .map_err(|_e| BlogError::CouldNotWritePostsFile)?;
+/// The posts file named by the env var, used as a default until one is picked in the app.
+pub fn default_posts_file() -> Option<PathBuf> {
+    env::var_os(POSTS_FILE_VAR_NAME).map(PathBuf::from)
+}
+
+fn modified_time(filename: &Path) -> Option<SystemTime> {
+    std::fs::metadata(filename)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Fail if the posts file was modified since the app loaded it at `loaded_modified`,
+/// so saving doesn't clobber changes made by another program.
+fn ensure_unchanged(filename: &Path, loaded_modified: Option<SystemTime>) -> BlogResult<()> {
+    if modified_time(filename) != loaded_modified {
+        return Err(BlogError::FileChangedExternally);
+    }
+    Ok(())
+}
+
+/// Load the posts file, starting an empty blog if it doesn't exist yet.
+/// Only a missing file starts fresh - anything else would overwrite a file we couldn't read.
+pub fn load(filename: &Path) -> BlogResult<BlogPostsForJson> {
+    match BlogPostsForJson::from_file(filename) {
+        Err(BlogError::FileNotFound) => Ok(BlogPostsForJson::default()),
+        result => result,
+    }
+}
+
+/// Load an encrypted posts file after [`load`] failed with [`BlogError::PassphraseRequired`].
+pub fn load_encrypted(filename: &Path, passphrase: &str) -> BlogResult<BlogPostsForJson> {
+    BlogPostsForJson::from_encrypted_file(filename, passphrase)
+}
+
+/// Apply `change` to a copy of `blog_posts` and save it, only updating `blog_posts` once the
+/// file has been written so a failed save leaves the in-memory state matching the file.
+fn save_change(
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+    change: impl FnOnce(&mut BlogPostsForJson) -> BlogResult<()>,
+) -> BlogResult<bool> {
+    ensure_unchanged(filename, blog_posts.modified)?;
+    let mut changed = blog_posts.clone();
+    change(&mut changed)?;
+    // A new file starts out as the default, version 0:
+    changed.schema_version = SCHEMA_VERSION;
+    changed.save_to_file(filename)?;
+    changed.modified = modified_time(filename);
+    *blog_posts = changed;
     Ok(true)
 }
+
+/// Save `blog_posts` to `filename`, failing with [`BlogError::FileChangedExternally`] if
+/// another program changed the file since it was loaded.
+pub fn save(blog_posts: &mut BlogPostsForJson, filename: &Path) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |_| Ok(()))
+}
+
+/// Put `blog_posts` back to `snapshot`, a copy taken before some earlier change, and save it.
+pub fn restore_snapshot(
+    snapshot: BlogPostsForJson,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        *blog_posts = snapshot;
+        Ok(())
+    })
+}
+
+/// Like [`publish`], but only report what it would do. `blog_posts` is what was loaded from
+/// `filename`, so an encrypted file needn't be decrypted again.
+pub fn publish_dry_run(
+    post: &Post,
+    blog_posts: &BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<PublishPlan> {
+    blog_posts.publish_plan(post, !filename.exists())
+}
+
+pub fn publish(post: Post, blog_posts: &mut BlogPostsForJson, filename: &Path) -> BlogResult<bool> {
+    post.validate(&blog_posts.page)?;
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.add_post(post);
+        Ok(())
+    })
+}
+
+pub fn update(
+    index: usize,
+    post: Post,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    post.validate(&blog_posts.page)?;
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.update_post(index, post)
+    })
+}
+
+pub fn delete(
+    index: usize,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.remove_post(index)
+    })
+}
+
+pub fn set_published(
+    index: usize,
+    published: bool,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.set_published(index, published)
+    })
+}
+
+pub fn restore_version(
+    index: usize,
+    version: usize,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.restore_version(index, version)
+    })
+}
+
+/// Encrypt the posts file with `passphrase` from now on, or store it as plain JSON again
+/// with `None`.
+pub fn set_passphrase(
+    passphrase: Option<String>,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.passphrase = passphrase;
+        Ok(())
+    })
+}
+
+pub fn save_footer_links(
+    footer_links: Vec<FooterLink>,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.set_footer_links(footer_links)
+    })
+}
+
+pub fn save_page(
+    page: Page,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| blog_posts.set_page(page))
+}
+
+/// Save the page settings and footer links together, without publishing anything.
+pub fn save_settings(
+    page: Page,
+    footer_links: Vec<FooterLink>,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<bool> {
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.set_page(page)?;
+        blog_posts.set_footer_links(footer_links)
+    })
+}
@@ -0,0 +1,63 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+
+use super::{BlogError, BlogResult};
+
+const LOG_FILE_NAME: &str = "blog.log";
+
+/// A publish, update or delete for [`log_action`] to record.
+pub struct LoggedAction<'a> {
+    /// Like `publish`, `update` or `delete`.
+    pub action: &'a str,
+    pub title: &'a str,
+    /// Why it failed, if it did.
+    pub error: Option<String>,
+}
+
+/// The log beside `posts_file`.
+pub fn log_file(posts_file: &Path) -> PathBuf {
+    posts_file.with_file_name(LOG_FILE_NAME)
+}
+
+/// Tabs and line breaks would split a field or a line of the log.
+fn log_field(text: &str) -> String {
+    text.replace(['\t', '\r', '\n'], " ")
+}
+
+/// Append a line to the log beside `posts_file` with the time, the action, the post's title
+/// and whether it worked, separated by tabs.
+pub fn log_action(posts_file: &Path, action: &LoggedAction) -> BlogResult<()> {
+    let path = log_file(posts_file);
+    let result = match &action.error {
+        Some(error) => format!("failed: {}", log_field(error)),
+        None => "ok".to_string(),
+    };
+    let line = format!(
+        "{}\t{}\t{}\t{}\n",
+        Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+        log_field(action.action),
+        log_field(action.title),
+        result
+    );
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|_| BlogError::CouldNotWriteLog(path.display().to_string()))
+}
+
+/// The last `count` lines of the log beside `posts_file`, or none if nothing's been logged.
+pub fn log_tail(posts_file: &Path, count: usize) -> BlogResult<Vec<String>> {
+    let path = log_file(posts_file);
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(_) => return Err(BlogError::UnableToReadLog(path.display().to_string())),
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines.len().saturating_sub(count);
+    Ok(lines[start..].iter().map(|line| line.to_string()).collect())
+}
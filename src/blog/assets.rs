@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use chrono::Local;
+
+use super::{BlogError, BlogResult};
+
+/// Images live in this folder next to the posts file and are referenced relative to it.
+const IMAGES_DIR_NAME: &str = "images";
+
+/// `photo.jpg` -> `photo-2.jpg`
+fn numbered_file_name(file_name: &Path, number: usize) -> String {
+    let stem = file_name
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match file_name.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, number, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, number),
+    }
+}
+
+/// `file_name`, with a number added if it's already taken in `images_dir`.
+fn unused_file_name(images_dir: &Path, file_name: &Path) -> String {
+    let mut name = file_name.to_string_lossy().to_string();
+    let mut number = 2;
+    while images_dir.join(&name).exists() {
+        name = numbered_file_name(file_name, number);
+        number += 1;
+    }
+    name
+}
+
+/// Copy `image` into the images folder beside `posts_file` and return the path to reference
+/// it by, e.g. `images/photo.jpg`. A number is added to the name if it's already taken.
+pub fn copy_image(image: &Path, posts_file: &Path) -> BlogResult<String> {
+    let error = || BlogError::CouldNotCopyImage(image.display().to_string());
+    let file_name = Path::new(image.file_name().ok_or_else(error)?);
+    let images_dir = posts_file.with_file_name(IMAGES_DIR_NAME);
+    std::fs::create_dir_all(&images_dir).map_err(|_| error())?;
+    let name = unused_file_name(&images_dir, file_name);
+    std::fs::copy(image, images_dir.join(&name)).map_err(|_| error())?;
+    Ok(format!("{}/{}", IMAGES_DIR_NAME, name))
+}
+
+/// Save `rgba` pixels, like a pasted screenshot, as a PNG named for when it was pasted in the
+/// images folder beside `posts_file`, and return the path to reference it by.
+pub fn save_image(rgba: &[u8], width: u32, height: u32, posts_file: &Path) -> BlogResult<String> {
+    let file_name = format!("pasted-{}.png", Local::now().format("%Y%m%d-%H%M%S"));
+    let error = || BlogError::CouldNotSaveImage(file_name.clone());
+    let image = image::RgbaImage::from_raw(width, height, rgba.to_vec()).ok_or_else(error)?;
+    let images_dir = posts_file.with_file_name(IMAGES_DIR_NAME);
+    std::fs::create_dir_all(&images_dir).map_err(|_| error())?;
+    let name = unused_file_name(&images_dir, Path::new(&file_name));
+    image
+        .save_with_format(images_dir.join(&name), image::ImageFormat::Png)
+        .map_err(|_| error())?;
+    Ok(format!("{}/{}", IMAGES_DIR_NAME, name))
+}
+
+/// The images in the images folder that `content` references, in order of first use.
+pub(super) fn referenced_assets(content: &[String]) -> Vec<String> {
+    let prefix = format!("]({}/", IMAGES_DIR_NAME);
+    let mut assets: Vec<String> = vec![];
+    for line in content {
+        let mut rest = line.as_str();
+        while let Some(start) = rest.find(&prefix) {
+            rest = &rest[start + 2..];
+            let Some(end) = rest.find(')') else {
+                break;
+            };
+            let asset = rest[..end].to_string();
+            // Exports copy assets by this path, so it mustn't climb out of the folder:
+            let escapes = asset.split('/').any(|part| part == "..");
+            if !escapes && !assets.contains(&asset) {
+                assets.push(asset);
+            }
+            rest = &rest[end..];
+        }
+    }
+    assets
+}
@@ -0,0 +1,47 @@
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+
+use super::{BlogError, BlogResult};
+
+/// Encrypted posts files start with this, followed by the salt, the nonce and the ciphertext.
+const MAGIC: &[u8] = b"WOA-ENCRYPTED-1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+pub(super) fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+fn cipher(passphrase: &str, salt: &[u8]) -> BlogResult<Aes256Gcm> {
+    let mut key = Key::<Aes256Gcm>::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|_| BlogError::CouldNotWritePostsFile)?;
+    Ok(Aes256Gcm::new(&key))
+}
+
+pub(super) fn encrypt(plaintext: &[u8], passphrase: &str) -> BlogResult<Vec<u8>> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher(passphrase, &salt)?
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| BlogError::CouldNotWritePostsFile)?;
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+/// Fails with `DecryptionFailed` on a wrong passphrase or a damaged file.
+pub(super) fn decrypt(data: &[u8], passphrase: &str) -> BlogResult<Vec<u8>> {
+    let data = data
+        .strip_prefix(MAGIC)
+        .filter(|data| data.len() > SALT_LEN + NONCE_LEN)
+        .ok_or(BlogError::DecryptionFailed)?;
+    let (salt, data) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    cipher(passphrase, salt)
+        .map_err(|_| BlogError::DecryptionFailed)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| BlogError::DecryptionFailed)
+}
@@ -0,0 +1,543 @@
+use std::ops::ControlFlow;
+use std::path::Path;
+
+use base64::Engine;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    BlogError, BlogPostsForJson, BlogResult, FooterLink, Page, PostForJson, Progress, slugify,
+    with_extra_extension,
+};
+
+const INDEX_FILE_NAME: &str = "index.html";
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// What [`export_html`] wrote, which it also saves as `manifest.json` beside the pages for
+/// deployment scripts.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ExportManifest {
+    pub files: Vec<ManifestEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    /// Relative to the export folder, with `/` separators.
+    pub path: String,
+    /// The slug and `woa_time` of the post the file is the page for, if it's one.
+    pub slug: Option<String>,
+    pub woa_time: Option<String>,
+}
+
+impl ExportManifest {
+    pub fn to_json_string(&self) -> BlogResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|_| BlogError::CouldNotWriteExport(MANIFEST_FILE_NAME.to_string()))
+    }
+}
+
+impl ManifestEntry {
+    fn file(path: &str) -> ManifestEntry {
+        ManifestEntry {
+            path: path.to_string(),
+            slug: None,
+            woa_time: None,
+        }
+    }
+}
+
+/// The posts that belong in an export, in the blog's order, leaving out the unpublished
+/// ones and, unless `include_future` is set, the ones dated in the future.
+fn published_posts(blog_posts: &BlogPostsForJson, include_future: bool) -> Vec<PostForJson> {
+    let now = Local::now();
+    blog_posts
+        .ordered_posts()
+        .filter(|post| post.published)
+        .filter(|post| {
+            include_future || blog_posts.post_time(post).map_or(true, |time| time <= now)
+        })
+        .cloned()
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn footer_html(footer_links: &[FooterLink]) -> String {
+    let links: Vec<String> = footer_links
+        .iter()
+        .map(|link| {
+            format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(&link.url),
+                escape_html(&link.label)
+            )
+        })
+        .collect();
+    format!("<footer>{}</footer>", links.join(" "))
+}
+
+const BUILT_IN_TEMPLATE: &str = "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{{site_title}}</title>\n<style>{{css}}</style>\n</head>\n<body>\n<h1>{{title}}</h1>\n{{content}}\n{{footer}}\n</body>\n</html>\n";
+/// A template without these would leave the posts out of the pages.
+const REQUIRED_PLACEHOLDERS: [&str; 2] = ["{{title}}", "{{content}}"];
+
+/// The page's template file, or the built-in one if it has none.
+fn load_template(page: &Page, filename: &Path) -> BlogResult<String> {
+    let Some(template) = &page.template else {
+        return Ok(BUILT_IN_TEMPLATE.to_string());
+    };
+    let path = filename.with_file_name(template);
+    let template = std::fs::read_to_string(&path)
+        .map_err(|e| BlogError::CouldNotReadTemplate(format!("{}: {}", path.display(), e)))?;
+    if let Some(placeholder) = REQUIRED_PLACEHOLDERS
+        .into_iter()
+        .find(|placeholder| !template.contains(placeholder))
+    {
+        return Err(BlogError::MissingTemplatePlaceholder(placeholder));
+    }
+    Ok(template)
+}
+
+/// Replace each `{{name}}` in `template` with its value from `values` in one pass, so
+/// placeholders inside the values are left alone. Unknown placeholders stay as they are.
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find("}}").and_then(|end| {
+            let name = &rest[2..end];
+            let (_, value) = values.iter().find(|(key, _)| *key == name)?;
+            Some((value, end + 2))
+        });
+        match value {
+            Some((value, len)) => {
+                filled.push_str(value);
+                rest = &rest[len..];
+            }
+            None => {
+                filled.push_str("{{");
+                rest = &rest[2..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+fn html_document(
+    template: &str,
+    page: &Page,
+    heading: &str,
+    body: &str,
+    footer_links: &[FooterLink],
+) -> String {
+    fill_template(
+        template,
+        &[
+            ("site_title", &escape_html(&page.title)),
+            ("css", &page.css),
+            ("title", &escape_html(heading)),
+            ("content", body),
+            ("footer", &footer_html(footer_links)),
+        ],
+    )
+}
+
+/// One file name per post, named after its slug and numbered when two posts share a slug.
+fn post_file_names(posts: &[PostForJson]) -> Vec<String> {
+    let mut file_names: Vec<String> = vec![];
+    for post in posts {
+        let mut file_name = format!("{}.html", post.slug);
+        let mut suffix = 2;
+        while file_names.contains(&file_name) || file_name == INDEX_FILE_NAME {
+            file_name = format!("{}-{}.html", post.slug, suffix);
+            suffix += 1;
+        }
+        file_names.push(file_name);
+    }
+    file_names
+}
+
+/// `![alt](src)` on a line of its own -> `<img src="src" alt="alt">`, with `src` passed
+/// through `resolve_src` first.
+fn image_html(line: &str, resolve_src: &dyn Fn(&str) -> String) -> Option<String> {
+    let (alt, src) = line.trim().strip_prefix("![")?.split_once("](")?;
+    let src = src.strip_suffix(')')?;
+    Some(format!(
+        "<img src=\"{}\" alt=\"{}\">",
+        escape_html(&resolve_src(src)),
+        escape_html(alt)
+    ))
+}
+
+fn post_body(post: &PostForJson) -> String {
+    post_body_with_images(post, &|src| src.to_string())
+}
+
+fn post_body_with_images(post: &PostForJson, resolve_src: &dyn Fn(&str) -> String) -> String {
+    let paragraphs: Vec<String> = post
+        .content
+        .iter()
+        .map(|line| {
+            let html = image_html(line, resolve_src).unwrap_or_else(|| escape_html(line));
+            format!("<p>{}</p>", html)
+        })
+        .collect();
+    format!(
+        "<article>\n<time>{}</time>\n{}\n</article>",
+        escape_html(&post.woa_time),
+        paragraphs.join("\n")
+    )
+}
+
+fn index_body(posts: &[PostForJson], file_names: &[String]) -> String {
+    let items: Vec<String> = posts
+        .iter()
+        .zip(file_names)
+        .map(|(post, file_name)| {
+            format!(
+                "<li><a href=\"{}\">{}</a> <time>{}</time></li>",
+                escape_html(file_name),
+                escape_html(&post.title),
+                escape_html(&post.woa_time)
+            )
+        })
+        .collect();
+    format!("<ul>\n{}\n</ul>", items.join("\n"))
+}
+
+/// Write `path` through a temporary file beside it, so it's never left half-written.
+fn write_atomically(
+    path: &Path,
+    write: impl FnOnce(&Path) -> std::io::Result<()>,
+) -> BlogResult<()> {
+    let temp_path = with_extra_extension(path, "tmp");
+    write(&temp_path)
+        .and_then(|_| std::fs::rename(&temp_path, path))
+        .map_err(|_| {
+            let _ = std::fs::remove_file(&temp_path);
+            BlogError::CouldNotWriteExport(path.display().to_string())
+        })
+}
+
+fn write_export_file(path: &Path, contents: &str) -> BlogResult<()> {
+    write_atomically(path, |temp_path| std::fs::write(temp_path, contents))
+}
+
+/// The files the posts reference from beside the posts file, each once.
+fn unique_assets(posts: &[PostForJson]) -> Vec<&String> {
+    let mut assets: Vec<&String> = vec![];
+    for asset in posts.iter().flat_map(|post| &post.assets) {
+        if !assets.contains(&asset) {
+            assets.push(asset);
+        }
+    }
+    assets
+}
+
+fn copy_asset(asset: &str, output_dir: &Path, filename: &Path) -> BlogResult<()> {
+    let destination = output_dir.join(asset);
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|_| BlogError::CouldNotWriteExport(destination.display().to_string()))?;
+    }
+    write_atomically(&destination, |temp_path| {
+        std::fs::copy(filename.with_file_name(asset), temp_path).map(|_| ())
+    })
+}
+
+/// Write a page per post and an `index.html` into `output_dir`, copying along the assets
+/// the posts reference from beside `filename`, and list them all in a `manifest.json`.
+pub fn export_html(
+    output_dir: &Path,
+    blog_posts: &BlogPostsForJson,
+    filename: &Path,
+    include_future: bool,
+) -> BlogResult<ExportManifest> {
+    export_html_with_progress(
+        output_dir,
+        blog_posts,
+        filename,
+        include_future,
+        &mut |_, _| ControlFlow::Continue(()),
+    )
+}
+
+/// Like [`export_html`], telling `progress` after each file is written. If it breaks, the
+/// export stops with [`BlogError::Cancelled`], leaving the files written so far.
+pub fn export_html_with_progress(
+    output_dir: &Path,
+    blog_posts: &BlogPostsForJson,
+    filename: &Path,
+    include_future: bool,
+    progress: Progress,
+) -> BlogResult<ExportManifest> {
+    std::fs::create_dir_all(output_dir)
+        .map_err(|_| BlogError::CouldNotWriteExport(output_dir.display().to_string()))?;
+    let template = load_template(&blog_posts.page, filename)?;
+    let posts = published_posts(blog_posts, include_future);
+    let file_names = post_file_names(&posts);
+    let assets = unique_assets(&posts);
+    // Every post's page, every asset, then the index and the manifest:
+    let total = posts.len() + assets.len() + 2;
+    let mut manifest = ExportManifest::default();
+    let mut done = 0;
+    let mut step = || {
+        done += 1;
+        match progress(done, total) {
+            ControlFlow::Continue(()) => Ok(()),
+            ControlFlow::Break(()) => Err(BlogError::Cancelled),
+        }
+    };
+    for (post, file_name) in posts.iter().zip(&file_names) {
+        let html = html_document(
+            &template,
+            &blog_posts.page,
+            &post.title,
+            &post_body(post),
+            &blog_posts.footer_links,
+        );
+        write_export_file(&output_dir.join(file_name), &html)?;
+        manifest.files.push(ManifestEntry {
+            path: file_name.clone(),
+            slug: Some(post.slug.clone()),
+            woa_time: Some(post.woa_time.clone()),
+        });
+        step()?;
+    }
+    for asset in assets {
+        copy_asset(asset, output_dir, filename)?;
+        manifest.files.push(ManifestEntry::file(asset));
+        step()?;
+    }
+    let index_html = html_document(
+        &template,
+        &blog_posts.page,
+        &blog_posts.page.title,
+        &index_body(&posts, &file_names),
+        &blog_posts.footer_links,
+    );
+    write_export_file(&output_dir.join(INDEX_FILE_NAME), &index_html)?;
+    manifest.files.push(ManifestEntry::file(INDEX_FILE_NAME));
+    step()?;
+    write_export_file(
+        &output_dir.join(MANIFEST_FILE_NAME),
+        &manifest.to_json_string()?,
+    )?;
+    step()?;
+    Ok(manifest)
+}
+
+fn image_mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// The asset at `src` beside `filename` as a `data:` URI. Anything that isn't one of the
+/// post's assets, or can't be read, is left as it is.
+fn data_uri(post: &PostForJson, src: &str, filename: &Path) -> String {
+    if !post.assets.iter().any(|asset| asset == src) {
+        return src.to_string();
+    }
+    let path = filename.with_file_name(src);
+    match std::fs::read(&path) {
+        Ok(bytes) => format!(
+            "data:{};base64,{}",
+            image_mime_type(&path),
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        ),
+        Err(_) => src.to_string(),
+    }
+}
+
+/// Write every published post into one HTML file at `output_path`, with the CSS inlined and
+/// the images the posts reference from beside `filename` embedded, so it can be passed
+/// around on its own.
+pub fn export_single_html(
+    output_path: &Path,
+    blog_posts: &BlogPostsForJson,
+    filename: &Path,
+    include_future: bool,
+) -> BlogResult<()> {
+    let articles: Vec<String> = published_posts(blog_posts, include_future)
+        .iter()
+        .map(|post| {
+            format!(
+                "<h2>{}</h2>\n{}",
+                escape_html(&post.title),
+                post_body_with_images(post, &|src| data_uri(post, src, filename))
+            )
+        })
+        .collect();
+    let template = load_template(&blog_posts.page, filename)?;
+    let html = html_document(
+        &template,
+        &blog_posts.page,
+        &blog_posts.page.title,
+        &articles.join("\n"),
+        &blog_posts.footer_links,
+    );
+    write_export_file(output_path, &html)
+}
+
+/// Write `post` to its own JSON file at `output_path`, for [`super::import_post`] to add to
+/// another blog. The images it references aren't included.
+pub fn export_post(output_path: &Path, post: &PostForJson) -> BlogResult<()> {
+    write_export_file(output_path, &post.to_json_string()?)
+}
+
+fn rss_item(blog_posts: &BlogPostsForJson, post: &PostForJson) -> String {
+    let pub_date = blog_posts
+        .post_time(post)
+        .map(|time| format!("<pubDate>{}</pubDate>", time.to_rfc2822()))
+        .unwrap_or_default();
+    format!(
+        "<item><title>{}</title><description>{}</description>{}</item>",
+        escape_html(&post.title),
+        escape_html(&post.content.join("\n")),
+        pub_date
+    )
+}
+
+pub fn export_rss(
+    output_path: &Path,
+    blog_posts: &BlogPostsForJson,
+    include_future: bool,
+) -> BlogResult<()> {
+    let items: Vec<String> = published_posts(blog_posts, include_future)
+        .iter()
+        .map(|post| rss_item(blog_posts, post))
+        .collect();
+    let link = blog_posts
+        .page
+        .site_url
+        .as_deref()
+        .map(|url| format!("<link>{}</link>\n", escape_html(url)))
+        .unwrap_or_default();
+    let rss = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n<title>{}</title>\n{}<description>{}</description>\n{}\n</channel>\n</rss>\n",
+        escape_html(&blog_posts.page.title),
+        link,
+        escape_html(&blog_posts.page.title),
+        items.join("\n")
+    );
+    write_export_file(output_path, &rss)
+}
+
+fn atom_updated(blog_posts: &BlogPostsForJson, post: &PostForJson) -> DateTime<Local> {
+    blog_posts.post_time(post).unwrap_or_else(|_| Local::now())
+}
+
+/// The slug alone can repeat, as in posts brought in from another blog, so the time it was
+/// posted goes in too.
+fn atom_id(blog_posts: &BlogPostsForJson, post: &PostForJson) -> String {
+    let time = blog_posts
+        .post_time(post)
+        .map(|time| time.timestamp().to_string())
+        .unwrap_or_else(|_| slugify(&post.woa_time));
+    format!("urn:woa:post:{}:{}", escape_html(&post.slug), time)
+}
+
+fn atom_entry(blog_posts: &BlogPostsForJson, post: &PostForJson) -> String {
+    format!(
+        "<entry><id>{}</id><title>{}</title><updated>{}</updated><content type=\"text\">{}</content></entry>",
+        atom_id(blog_posts, post),
+        escape_html(&post.title),
+        atom_updated(blog_posts, post).to_rfc3339(),
+        escape_html(&post.content.join("\n"))
+    )
+}
+
+pub fn export_atom(
+    output_path: &Path,
+    blog_posts: &BlogPostsForJson,
+    include_future: bool,
+) -> BlogResult<()> {
+    let posts = published_posts(blog_posts, include_future);
+    let updated = posts
+        .iter()
+        .map(|post| atom_updated(blog_posts, post))
+        .max()
+        .unwrap_or_else(Local::now);
+    let entries: Vec<String> = posts
+        .iter()
+        .map(|post| atom_entry(blog_posts, post))
+        .collect();
+    let atom = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n<id>urn:woa:{}</id>\n<title>{}</title>\n<author><name>{}</name></author>\n<updated>{}</updated>\n{}\n</feed>\n",
+        escape_html(&slugify(&blog_posts.page.title)),
+        escape_html(&blog_posts.page.title),
+        escape_html(&blog_posts.page.title),
+        updated.to_rfc3339(),
+        entries.join("\n")
+    );
+    write_export_file(output_path, &atom)
+}
+
+/// Break `line` between words into lines of at most `width` characters. A word longer than
+/// `width` gets a line of its own rather than being split, and an empty line stays one
+/// empty line.
+pub fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut current = String::new();
+    for word in line.split_whitespace() {
+        let current_width = current.chars().count();
+        if current_width > 0 && current_width + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn text_entry(post: &PostForJson, width: usize) -> String {
+    let mut entry = format!(
+        "{}\n{}\n{}\n\n",
+        post.woa_time,
+        post.title,
+        "=".repeat(post.title.chars().count())
+    );
+    for line in post.content.iter().flat_map(|line| wrap_line(line, width)) {
+        entry.push_str(&line);
+        entry.push('\n');
+    }
+    entry
+}
+
+/// Write the published posts as plain text, e.g. for a newsletter: each under its date and
+/// an underlined title, with the content wrapped to `width` columns.
+pub fn export_text(
+    output_path: &Path,
+    blog_posts: &BlogPostsForJson,
+    width: usize,
+    include_future: bool,
+) -> BlogResult<()> {
+    let entries: Vec<String> = published_posts(blog_posts, include_future)
+        .iter()
+        .map(|post| text_entry(post, width))
+        .collect();
+    write_export_file(output_path, &entries.join("\n"))
+}
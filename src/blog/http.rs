@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BlogError, BlogResult, Page, Post};
+
+/// Where published posts go.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub enum PublishTarget {
+    /// Into the posts file.
+    #[default]
+    File,
+    /// POSTed as JSON to a server at this URL, which keeps the posts itself.
+    Http(String),
+}
+
+/// Send `post` to `url` as the JSON it would be stored as in the posts file, instead of
+/// writing it to a file. A response other than 2xx fails with [`BlogError::HttpError`].
+pub fn publish_to_url(post: Post, page: &Page, url: &str) -> BlogResult<()> {
+    post.validate(page)?;
+    let response = reqwest::blocking::Client::new()
+        .post(url)
+        .json(&post.for_json(&page.time_format))
+        .send()
+        .map_err(|e| BlogError::HttpRequestFailed(e.to_string()))?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    Err(BlogError::HttpError {
+        status: status.as_u16(),
+        body: response.text().unwrap_or_default(),
+    })
+}
@@ -0,0 +1,110 @@
+use std::ops::ControlFlow;
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+
+use super::{
+    BlogError, BlogPostsForJson, BlogResult, PostForJson, Progress, format_time, parse_time,
+    save_change, slugify, split_on_line_breaks,
+};
+
+const IMPORT_EXTENSIONS: [&str; 2] = ["md", "txt"];
+
+/// Build a post from a Markdown or text file: the title comes from a leading `# heading`
+/// if there is one, otherwise from the file name.
+fn post_from_file(path: &Path, time_format: &str) -> Option<PostForJson> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let modified: DateTime<Local> = std::fs::metadata(path).ok()?.modified().ok()?.into();
+    let mut content = split_on_line_breaks(text.trim_end());
+    let heading = content
+        .first()
+        .and_then(|line| line.strip_prefix("# "))
+        .map(|heading| heading.trim().to_string());
+    let title = match heading {
+        Some(heading) => {
+            content.remove(0);
+            heading
+        }
+        None => path.file_stem()?.to_string_lossy().to_string(),
+    };
+    Some(PostForJson {
+        woa_time: format_time(modified, time_format),
+        slug: slugify(&title),
+        title,
+        content,
+        tags: vec![],
+        published: true,
+        assets: vec![],
+        history: vec![],
+    })
+}
+
+/// Import every `.md`/`.txt` file in `dir` as a post, skipping files that can't be read.
+/// Returns how many posts were imported.
+pub fn import_dir(
+    dir: &Path,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<usize> {
+    import_dir_with_progress(dir, blog_posts, filename, &mut |_, _| {
+        ControlFlow::Continue(())
+    })
+}
+
+/// Like [`import_dir`], telling `progress` after each file is read. If it breaks, nothing
+/// is imported and this fails with [`BlogError::Cancelled`].
+pub fn import_dir_with_progress(
+    dir: &Path,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+    progress: Progress,
+) -> BlogResult<usize> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|_| BlogError::UnableToReadImportDir(dir.display().to_string()))?;
+    let paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| IMPORT_EXTENSIONS.iter().any(|e| extension == *e))
+        })
+        .collect();
+    let mut imported: Vec<PostForJson> = vec![];
+    for (done, path) in paths.iter().enumerate() {
+        imported.extend(post_from_file(path, &blog_posts.page.time_format));
+        if progress(done + 1, paths.len()).is_break() {
+            return Err(BlogError::Cancelled);
+        }
+    }
+    let count = imported.len();
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.posts.extend(imported);
+        blog_posts.sort_by_time();
+        Ok(())
+    })?;
+    Ok(count)
+}
+
+/// Add the post in `path`, written by [`super::export_post`], to `blog_posts`. Its slug gets
+/// a number added if another post already has it, and it's dated now if its date isn't in
+/// this blog's time format. Returns the slug it was added under.
+pub fn import_post(
+    path: &Path,
+    blog_posts: &mut BlogPostsForJson,
+    filename: &Path,
+) -> BlogResult<String> {
+    let json_string = std::fs::read_to_string(path)
+        .map_err(|_| BlogError::UnableToReadPostFile(path.display().to_string()))?;
+    let mut post = PostForJson::from_json_string(&json_string)?;
+    post.slug = blog_posts.unique_slug(&post.slug);
+    if parse_time(&post.woa_time, &blog_posts.page.time_format).is_err() {
+        post.woa_time = format_time(Local::now(), &blog_posts.page.time_format);
+    }
+    let slug = post.slug.clone();
+    save_change(blog_posts, filename, |blog_posts| {
+        blog_posts.posts.push(post);
+        blog_posts.sort_by_time();
+        Ok(())
+    })?;
+    Ok(slug)
+}
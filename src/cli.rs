@@ -0,0 +1,100 @@
+//! Command-line mode for publishing without opening the window.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use azifirsteframe::blog;
+
+#[derive(Parser)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Publish a post to a posts file
+    Publish {
+        /// The posts file to publish to
+        #[arg(long)]
+        file: PathBuf,
+        /// The title of the post
+        #[arg(long)]
+        title: String,
+        /// A Markdown or text file holding the content of the post
+        #[arg(long)]
+        content_file: PathBuf,
+    },
+    /// Export a posts file as a site and print the manifest of what was written
+    Export {
+        /// The posts file to export
+        #[arg(long)]
+        file: PathBuf,
+        /// The folder to write the site into
+        #[arg(long)]
+        output: PathBuf,
+        /// Also export posts dated in the future
+        #[arg(long)]
+        include_future: bool,
+    },
+}
+
+/// Run `command` and return the process exit code.
+pub fn run(command: Command) -> i32 {
+    match command {
+        Command::Publish {
+            file,
+            title,
+            content_file,
+        } => {
+            let content = match std::fs::read_to_string(&content_file) {
+                Ok(content) => content,
+                Err(e) => {
+                    eprintln!("Could not read {}: {}", content_file.display(), e);
+                    return 1;
+                }
+            };
+            let post = blog::Post {
+                title,
+                content: blog::split_on_line_breaks(content.trim_end()),
+                tags: vec![],
+                published: true,
+                scheduled_time: None,
+            };
+            let result = blog::load(&file)
+                .and_then(|mut blog_posts| blog::publish(post, &mut blog_posts, &file));
+            match result {
+                Ok(_) => {
+                    println!("Publish successful");
+                    0
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    1
+                }
+            }
+        }
+        Command::Export {
+            file,
+            output,
+            include_future,
+        } => {
+            let result = blog::load(&file)
+                .and_then(|blog_posts| {
+                    blog::export_html(&output, &blog_posts, &file, include_future)
+                })
+                .and_then(|manifest| manifest.to_json_string());
+            match result {
+                Ok(manifest_json) => {
+                    println!("{}", manifest_json);
+                    0
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    1
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! `config.toml` in the platform config folder, for setting the posts file and editor
+//! preferences by hand. Anything left out of it keeps the value the app remembered.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::fonts::EditorFont;
+
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Config {
+    /// Opened at startup unless the `BLOG_POSTS_FILE` env var names another.
+    pub posts_file: Option<PathBuf>,
+    pub theme: Option<egui::ThemePreference>,
+    pub soft_wrap: Option<bool>,
+    pub line_numbers: Option<bool>,
+    pub editor_font: Option<EditorFont>,
+    pub editor_font_size: Option<f32>,
+}
+
+impl Config {
+    /// The config file's settings, or none if there isn't one or it can't be read.
+    pub fn load() -> Config {
+        let Some(path) = config_file() else {
+            return Config::default();
+        };
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return Config::default();
+        };
+        toml::from_str(&text).unwrap_or_else(|e| {
+            log::warn!("Ignoring {}: {}", path.display(), e);
+            Config::default()
+        })
+    }
+
+    pub fn save(&self) {
+        let Some(path) = config_file() else {
+            return;
+        };
+        let result = toml::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|text| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(&path, text)
+            });
+        if let Err(e) = result {
+            log::warn!("Could not write config to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn config_file() -> Option<PathBuf> {
+    let dirs = directories::ProjectDirs::from("com", "wizardofarc", "woablogupdater")?;
+    Some(dirs.config_dir().join(CONFIG_FILE_NAME))
+}
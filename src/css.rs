@@ -0,0 +1,168 @@
+//! Just enough of the page CSS to make the preview look like the exported site: the page's
+//! background, text and link colors and its font size. Anything else is ignored.
+
+use egui::Color32;
+
+/// The font size CSS `em`/`rem` sizes are relative to, as browsers default to.
+const CSS_BASE_FONT_SIZE: f32 = 16.0;
+/// Selectors whose rules apply to the whole page.
+const PAGE_SELECTORS: [&str; 4] = ["html", "body", ":root", "*"];
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub struct PreviewStyle {
+    pub background: Option<Color32>,
+    pub text_color: Option<Color32>,
+    pub link_color: Option<Color32>,
+    /// In points, which are the same as CSS pixels.
+    pub font_size: Option<f32>,
+}
+
+impl PreviewStyle {
+    /// The style of `css`, with later rules winning as they do in a browser. Properties that
+    /// don't parse are skipped rather than failing the rest.
+    pub fn from_css(css: &str) -> PreviewStyle {
+        let mut style = PreviewStyle::default();
+        for (selectors, declarations) in rules(&strip_comments(css)) {
+            let selectors: Vec<&str> = selectors.split(',').map(str::trim).collect();
+            let is_page = selectors.iter().any(|s| PAGE_SELECTORS.contains(s));
+            let is_link = selectors.iter().any(|s| *s == "a" || s.starts_with("a:"));
+            for (property, value) in declarations {
+                match property.as_str() {
+                    "background" | "background-color" if is_page => {
+                        // `background` can list an image etc. too; take the first color in it.
+                        let color = parse_color(&value)
+                            .or_else(|| value.split_whitespace().find_map(parse_color));
+                        style.background = color.or(style.background);
+                    }
+                    "color" if is_page => {
+                        style.text_color = parse_color(&value).or(style.text_color)
+                    }
+                    "color" if is_link => {
+                        style.link_color = parse_color(&value).or(style.link_color)
+                    }
+                    "font-size" if is_page => {
+                        style.font_size = parse_font_size(&value).or(style.font_size);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        style
+    }
+
+    /// Apply the style to the preview's `ui`, scaling every text style so the body text has
+    /// the page's font size.
+    pub fn apply(&self, ui: &mut egui::Ui) {
+        if let Some(color) = self.text_color {
+            ui.visuals_mut().override_text_color = Some(color);
+        }
+        if let Some(color) = self.link_color {
+            ui.visuals_mut().hyperlink_color = color;
+        }
+        if let Some(size) = self.font_size {
+            let style = ui.style_mut();
+            let body_size = style
+                .text_styles
+                .get(&egui::TextStyle::Body)
+                .map_or(size, |font| font.size);
+            let scale = size / body_size;
+            for font in style.text_styles.values_mut() {
+                font.size *= scale;
+            }
+        }
+    }
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut stripped = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map_or("", |end| &rest[start + 2 + end + 2..]);
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Each rule's selector list and its `property: value` declarations, lowercased. At-rules
+/// like `@media` are skipped along with what's in them.
+fn rules(css: &str) -> Vec<(String, Vec<(String, String)>)> {
+    css.split('}')
+        .filter_map(|rule| rule.split_once('{'))
+        .filter(|(selectors, _)| !selectors.contains('@'))
+        .map(|(selectors, body)| {
+            let declarations = body
+                .split(';')
+                .filter_map(|declaration| declaration.split_once(':'))
+                .map(|(property, value)| {
+                    let value = value.trim().trim_end_matches("!important").trim();
+                    (property.trim().to_lowercase(), value.to_lowercase())
+                })
+                .collect();
+            (selectors.trim().to_lowercase(), declarations)
+        })
+        .collect()
+}
+
+fn parse_color(value: &str) -> Option<Color32> {
+    let value = value.trim();
+    if value.starts_with('#') {
+        return Color32::from_hex(value).ok();
+    }
+    if let Some(arguments) = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let parts: Vec<&str> = arguments
+            .split([',', ' ', '/'])
+            .filter(|part| !part.is_empty())
+            .collect();
+        let channel = |part: &str| part.parse::<f32>().ok().map(|c| c.clamp(0.0, 255.0) as u8);
+        let alpha = match parts.get(3) {
+            Some(alpha) => match alpha.strip_suffix('%') {
+                Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+                None => alpha.parse::<f32>().ok()?,
+            },
+            None => 1.0,
+        };
+        return Some(Color32::from_rgba_unmultiplied(
+            channel(parts.first()?)?,
+            channel(parts.get(1)?)?,
+            channel(parts.get(2)?)?,
+            (alpha.clamp(0.0, 1.0) * 255.0) as u8,
+        ));
+    }
+    let color = match value {
+        "black" => Color32::BLACK,
+        "white" => Color32::WHITE,
+        "gray" | "grey" => Color32::GRAY,
+        "red" => Color32::RED,
+        "green" => Color32::DARK_GREEN,
+        "blue" => Color32::BLUE,
+        "yellow" => Color32::YELLOW,
+        "transparent" => Color32::TRANSPARENT,
+        _ => return None,
+    };
+    Some(color)
+}
+
+fn parse_font_size(value: &str) -> Option<f32> {
+    let number = |suffix: &str| value.strip_suffix(suffix)?.trim().parse::<f32>().ok();
+    let size = if let Some(px) = number("px") {
+        px
+    } else if let Some(pt) = number("pt") {
+        pt * 4.0 / 3.0
+    } else if let Some(rem) = number("rem") {
+        rem * CSS_BASE_FONT_SIZE
+    } else if let Some(em) = number("em") {
+        em * CSS_BASE_FONT_SIZE
+    } else if let Some(percent) = number("%") {
+        percent / 100.0 * CSS_BASE_FONT_SIZE
+    } else {
+        return None;
+    };
+    (size > 0.0).then_some(size)
+}
@@ -0,0 +1,38 @@
+//! How tightly the whole UI is spaced, for fitting more on a small screen or less on a big one.
+
+use egui::Vec2;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum Density {
+    Compact,
+    /// Close to egui's own spacing.
+    #[default]
+    Comfortable,
+}
+
+impl Density {
+    pub const ALL: [Density; 2] = [Density::Compact, Density::Comfortable];
+
+    /// The key of its name in [`crate::i18n`].
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Comfortable => "comfortable",
+        }
+    }
+
+    /// Set the spacing of both the light and the dark style.
+    pub fn apply(self, ctx: &egui::Context) {
+        let (item_spacing, button_padding, interact_height, indent) = match self {
+            Density::Compact => (Vec2::new(4.0, 2.0), Vec2::new(2.0, 0.0), 16.0, 14.0),
+            Density::Comfortable => (Vec2::new(8.0, 5.0), Vec2::new(6.0, 3.0), 22.0, 21.0),
+        };
+        ctx.all_styles_mut(|style| {
+            style.spacing.item_spacing = item_spacing;
+            style.spacing.button_padding = button_padding;
+            style.spacing.interact_size.y = interact_height;
+            style.spacing.indent = indent;
+        });
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+const DRAFT_FILE_NAME: &str = "draft.json";
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Draft {
+    pub title: String,
+    pub content: String,
+    #[serde(default)]
+    pub tags: String,
+}
+
+impl Draft {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_empty() && self.content.is_empty()
+    }
+
+    pub fn save(&self, posts_file: &Path) {
+        let path = draft_file(posts_file);
+        let result = serde_json::to_string(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(&path, json));
+        if let Err(e) = result {
+            log::warn!("Could not autosave draft to {}: {}", path.display(), e);
+        }
+    }
+
+    pub fn load(posts_file: &Path) -> Option<Draft> {
+        let json = std::fs::read_to_string(draft_file(posts_file)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn clear(posts_file: &Path) {
+        let path = draft_file(posts_file);
+        if path.exists()
+            && let Err(e) = std::fs::remove_file(&path)
+        {
+            log::warn!("Could not remove draft {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// The autosaved draft lives next to the posts file it belongs to.
+fn draft_file(posts_file: &Path) -> PathBuf {
+    posts_file.with_file_name(DRAFT_FILE_NAME)
+}
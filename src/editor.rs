@@ -0,0 +1,154 @@
+//! Helpers for editing the text of a `TextEdit` around its cursor from outside the widget.
+
+use std::ops::Range;
+
+use egui::text::{CCursor, CCursorRange};
+use egui::text_edit::TextEditState;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{self, Language};
+
+/// What Tab inserts in the content editor when it indents instead of moving focus.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Indent {
+    Spaces(usize),
+    Tab,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(4)
+    }
+}
+
+impl Indent {
+    pub const CHOICES: [Indent; 3] = [Indent::Spaces(2), Indent::Spaces(4), Indent::Tab];
+
+    /// The key of its name in [`crate::i18n`].
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Indent::Spaces(_) => "indent_spaces",
+            Indent::Tab => "indent_tab",
+        }
+    }
+
+    pub fn label(self, language: Language) -> String {
+        match self {
+            Indent::Spaces(width) => i18n::tr_args(language, self.label_key(), &[&width]),
+            Indent::Tab => i18n::tr(language, self.label_key()).to_string(),
+        }
+    }
+
+    pub fn text(self) -> String {
+        match self {
+            Indent::Spaces(width) => " ".repeat(width),
+            Indent::Tab => "\t".to_string(),
+        }
+    }
+
+    /// How many leading spaces one level of dedenting takes off; a leading tab always goes.
+    fn width(self) -> usize {
+        match self {
+            Indent::Spaces(width) => width,
+            Indent::Tab => 4,
+        }
+    }
+}
+
+/// The selected character range of the `TextEdit` with `id`, or `None` if it never had a cursor.
+pub fn selection(ctx: &egui::Context, id: egui::Id) -> Option<Range<usize>> {
+    let range = TextEditState::load(ctx, id)?.cursor.char_range()?;
+    let (start, end) = (range.primary.index, range.secondary.index);
+    Some(start.min(end)..start.max(end))
+}
+
+/// Select the character range `range` in the `TextEdit` with `id`.
+pub fn set_selection(ctx: &egui::Context, id: egui::Id, range: Range<usize>) {
+    let mut state = TextEditState::load(ctx, id).unwrap_or_default();
+    state.cursor.set_char_range(Some(CCursorRange::two(
+        CCursor::new(range.start),
+        CCursor::new(range.end),
+    )));
+    state.store(ctx, id);
+}
+
+/// The selection clamped to `text`, or the end of `text` if there is no cursor yet.
+fn selection_in(ctx: &egui::Context, id: egui::Id, text: &str) -> Range<usize> {
+    let text_len = text.chars().count();
+    selection(ctx, id)
+        .map(|range| range.start.min(text_len)..range.end.min(text_len))
+        .unwrap_or(text_len..text_len)
+}
+
+pub fn byte_index(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len())
+}
+
+/// Replace the characters in `range` of `text` with `replacement`.
+pub fn replace_chars(text: &mut String, range: Range<usize>, replacement: &str) {
+    let start = byte_index(text, range.start);
+    let end = byte_index(text, range.end);
+    text.replace_range(start..end, replacement);
+}
+
+/// Insert `insert` at the cursor, replacing the selection if there is one, and place the
+/// cursor after it. Without a known cursor the text goes at the end.
+pub fn insert_at_cursor(ctx: &egui::Context, id: egui::Id, text: &mut String, insert: &str) {
+    let range = selection_in(ctx, id, text);
+    replace_chars(text, range.clone(), insert);
+    let cursor = range.start + insert.chars().count();
+    set_selection(ctx, id, cursor..cursor);
+}
+
+/// Wrap the selection in `marker` (e.g. `**` for bold). Without a selection the markers are
+/// inserted as a pair with the cursor between them.
+pub fn wrap_selection(ctx: &egui::Context, id: egui::Id, text: &mut String, marker: &str) {
+    let range = selection_in(ctx, id, text);
+    let marker_len = marker.chars().count();
+    replace_chars(text, range.end..range.end, marker);
+    replace_chars(text, range.start..range.start, marker);
+    set_selection(ctx, id, range.start + marker_len..range.end + marker_len);
+}
+
+/// The character index of the start of the line holding the character index `index`.
+fn line_start(text: &str, index: usize) -> usize {
+    let before = &text[..byte_index(text, index)];
+    before
+        .rfind('\n')
+        .map(|newline| before[..=newline].chars().count())
+        .unwrap_or(0)
+}
+
+/// Insert `prefix` (e.g. `## ` for a heading) at the start of the line holding the cursor.
+pub fn prefix_line(ctx: &egui::Context, id: egui::Id, text: &mut String, prefix: &str) {
+    let range = selection_in(ctx, id, text);
+    let line_start = line_start(text, range.start);
+    replace_chars(text, line_start..line_start, prefix);
+    let prefix_len = prefix.chars().count();
+    set_selection(ctx, id, range.start + prefix_len..range.end + prefix_len);
+}
+
+/// Take one level of `indent` off the start of the line holding the cursor: a tab, or up to
+/// as many spaces as `indent` is wide. The cursor stays on the same text.
+pub fn dedent_line(ctx: &egui::Context, id: egui::Id, text: &mut String, indent: Indent) {
+    let range = selection_in(ctx, id, text);
+    let line_start = line_start(text, range.start);
+    let line = &text[byte_index(text, line_start)..];
+    let removed = if line.starts_with('\t') {
+        1
+    } else {
+        line.chars()
+            .take(indent.width())
+            .take_while(|c| *c == ' ')
+            .count()
+    };
+    if removed == 0 {
+        return;
+    }
+    replace_chars(text, line_start..line_start + removed, "");
+    let shift = |index: usize| index.saturating_sub(removed).max(line_start);
+    set_selection(ctx, id, shift(range.start)..shift(range.end));
+}
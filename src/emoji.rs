@@ -0,0 +1,98 @@
+//! The emoji the editor's picker offers, a few dozen common ones rather than all of Unicode.
+//! Each comes with words to search for it by.
+
+pub struct Category {
+    pub name: &'static str,
+    /// Each emoji and its search words.
+    pub emoji: &'static [(&'static str, &'static str)],
+}
+
+pub const CATEGORIES: &[Category] = &[
+    Category {
+        name: "Smileys",
+        emoji: &[
+            ("😀", "grinning smile happy"),
+            ("😂", "joy laughing tears"),
+            ("😊", "blush smile happy"),
+            ("😍", "heart eyes love"),
+            ("😉", "wink"),
+            ("😎", "sunglasses cool"),
+            ("🤔", "thinking hmm"),
+            ("😴", "sleeping tired"),
+            ("😢", "crying sad tear"),
+            ("😡", "angry mad"),
+            ("😱", "scream shocked"),
+            ("🙃", "upside down silly"),
+        ],
+    },
+    Category {
+        name: "Gestures",
+        emoji: &[
+            ("👍", "thumbs up yes good"),
+            ("👎", "thumbs down no bad"),
+            ("👏", "clap applause"),
+            ("🙏", "pray thanks please"),
+            ("👋", "wave hello bye"),
+            ("💪", "muscle strong"),
+            ("✌", "victory peace"),
+            ("👌", "ok perfect"),
+        ],
+    },
+    Category {
+        name: "Nature",
+        emoji: &[
+            ("☀", "sun sunny weather"),
+            ("🌧", "rain weather"),
+            ("❄", "snow cold weather"),
+            ("🌈", "rainbow"),
+            ("🌙", "moon night"),
+            ("⭐", "star"),
+            ("🌸", "blossom flower spring"),
+            ("🌲", "tree forest"),
+            ("🐱", "cat"),
+            ("🐶", "dog"),
+        ],
+    },
+    Category {
+        name: "Food",
+        emoji: &[
+            ("☕", "coffee tea hot drink"),
+            ("🍵", "tea drink"),
+            ("🍺", "beer drink"),
+            ("🍷", "wine drink"),
+            ("🍕", "pizza"),
+            ("🍰", "cake dessert"),
+            ("🍎", "apple fruit"),
+            ("🍞", "bread"),
+        ],
+    },
+    Category {
+        name: "Symbols",
+        emoji: &[
+            ("❤", "heart love red"),
+            ("✨", "sparkles magic"),
+            ("🔥", "fire hot"),
+            ("🎉", "party celebrate tada"),
+            ("✅", "check done yes"),
+            ("❌", "cross no wrong"),
+            ("⚠", "warning caution"),
+            ("💡", "idea light bulb"),
+            ("📚", "books reading"),
+            ("✈", "plane travel"),
+            ("🎵", "music note"),
+            ("→", "arrow right"),
+        ],
+    },
+];
+
+/// The emoji in `category` with every word of `query` in their search words, all of them if
+/// `query` is blank.
+pub fn matching(category: &Category, query: &str) -> Vec<&'static str> {
+    let query = query.to_lowercase();
+    category
+        .emoji
+        .iter()
+        .filter(|(_, words)| query.split_whitespace().all(|word| words.contains(word)))
+        .map(|(emoji, _)| *emoji)
+        .collect()
+}
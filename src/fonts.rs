@@ -0,0 +1,61 @@
+//! The font the content editor is shown in. Everything else keeps egui's own fonts.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use egui::{FontData, FontDefinitions, FontFamily};
+use serde::{Deserialize, Serialize};
+
+const CUSTOM_FONT_NAME: &str = "editor_custom";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum EditorFont {
+    #[default]
+    Proportional,
+    Monospace,
+    /// A `.ttf`/`.otf` loaded with `install_custom_font`.
+    Custom,
+}
+
+impl EditorFont {
+    /// The key of its name in [`crate::i18n`].
+    pub fn label_key(self) -> &'static str {
+        match self {
+            EditorFont::Proportional => "font_proportional",
+            EditorFont::Monospace => "font_monospace",
+            EditorFont::Custom => "font_custom",
+        }
+    }
+
+    pub fn family(self) -> FontFamily {
+        match self {
+            EditorFont::Proportional => FontFamily::Proportional,
+            EditorFont::Monospace => FontFamily::Monospace,
+            EditorFont::Custom => FontFamily::Name(CUSTOM_FONT_NAME.into()),
+        }
+    }
+}
+
+/// Register the font file at `path` as the `EditorFont::Custom` family, falling back to the
+/// proportional fonts for glyphs it doesn't have.
+pub fn install_custom_font(ctx: &egui::Context, path: &Path) -> std::io::Result<()> {
+    let bytes = std::fs::read(path)?;
+    let mut fonts = FontDefinitions::default();
+    fonts.font_data.insert(
+        CUSTOM_FONT_NAME.to_owned(),
+        Arc::new(FontData::from_owned(bytes)),
+    );
+    let mut family = vec![CUSTOM_FONT_NAME.to_owned()];
+    family.extend(
+        fonts
+            .families
+            .get(&FontFamily::Proportional)
+            .cloned()
+            .unwrap_or_default(),
+    );
+    fonts
+        .families
+        .insert(FontFamily::Name(CUSTOM_FONT_NAME.into()), family);
+    ctx.set_fonts(fonts);
+    Ok(())
+}
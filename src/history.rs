@@ -0,0 +1,78 @@
+use std::collections::VecDeque;
+
+const MAX_ENTRIES: usize = 100;
+pub const DEBOUNCE_SECS: f64 = 1.0;
+
+/// Undo/redo snapshots of the content editor.
+///
+/// Edits are only snapshotted once typing pauses for `DEBOUNCE_SECS`, so undo steps back
+/// a burst of typing at a time rather than single keystrokes.
+#[derive(Default)]
+pub struct EditHistory {
+    undo: VecDeque<String>,
+    redo: Vec<String>,
+    committed: String,
+    last_edit: f64,
+}
+
+impl EditHistory {
+    pub fn new(content: &str) -> Self {
+        Self {
+            committed: content.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn reset(&mut self, content: &str) {
+        *self = Self::new(content);
+    }
+
+    /// Call once per frame with the current content. Returns `true` while an edit is
+    /// waiting for the debounce to expire so the caller can schedule a repaint.
+    pub fn track(&mut self, content: &str, now: f64, changed: bool) -> bool {
+        if changed {
+            self.last_edit = now;
+        }
+        if content == self.committed {
+            return false;
+        }
+        if now - self.last_edit < DEBOUNCE_SECS {
+            return true;
+        }
+        self.commit(content);
+        false
+    }
+
+    pub fn undo(&mut self, content: &mut String) {
+        self.commit(content);
+        if let Some(previous) = self.undo.pop_back() {
+            self.redo.push(std::mem::replace(content, previous));
+            self.committed = content.clone();
+        }
+    }
+
+    pub fn redo(&mut self, content: &mut String) {
+        // An edit since the last undo invalidates the redo stack:
+        self.commit(content);
+        if let Some(next) = self.redo.pop() {
+            self.push_undo(std::mem::replace(content, next));
+            self.committed = content.clone();
+        }
+    }
+
+    fn commit(&mut self, content: &str) {
+        if content == self.committed {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.committed, content.to_owned());
+        self.push_undo(previous);
+        self.redo.clear();
+    }
+
+    fn push_undo(&mut self, snapshot: String) {
+        if self.undo.len() == MAX_ENTRIES {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(snapshot);
+    }
+}
@@ -0,0 +1,1204 @@
+//! Translations of the UI text, looked up by key. Keys missing from a language fall back to
+//! English, and keys missing from English show as the key itself.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::English, Language::French, Language::German];
+
+    /// The language's own name for itself, for the language selector.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+        }
+    }
+
+    /// The language of the system locale, or English if it isn't one there are
+    /// translations for.
+    pub fn from_system() -> Language {
+        let locale = sys_locale::get_locale().unwrap_or_default().to_lowercase();
+        match locale.split(['-', '_']).next() {
+            Some("fr") => Language::French,
+            Some("de") => Language::German,
+            _ => Language::English,
+        }
+    }
+
+    fn translations(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => ENGLISH,
+            Language::French => FRENCH,
+            Language::German => GERMAN,
+        }
+    }
+}
+
+pub fn tr(language: Language, key: &'static str) -> &'static str {
+    let lookup = |translations: &'static [(&'static str, &'static str)]| {
+        translations
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, text)| *text)
+    };
+    lookup(language.translations())
+        .or_else(|| lookup(ENGLISH))
+        .unwrap_or(key)
+}
+
+/// [`tr`] with each `{}` in the text filled in with the next of `args`.
+pub fn tr_args(language: Language, key: &'static str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut parts = tr(language, key).split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    let mut args = args.iter();
+    for part in parts {
+        if let Some(arg) = args.next() {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("app_title", "Wizard Of Arc Blog Entry Maker"),
+    ("saving", "Saving…"),
+    ("enter_title", "Enter Title"),
+    ("enter_content", "Enter Content"),
+    ("preview", "Preview"),
+    ("wrap", "Wrap"),
+    ("line_numbers", "Line numbers"),
+    ("zen", "Zen"),
+    ("zen_hover", "Distraction-free writing (F11)"),
+    ("tags", "Tags:"),
+    ("tags_hint", "comma, separated"),
+    ("published", "Published"),
+    (
+        "published_hover",
+        "Unpublished posts are left out of the exported site and feeds",
+    ),
+    ("update", "Update"),
+    ("publish", "Publish"),
+    (
+        "choose_posts_file_first",
+        "Choose a posts file first (or set the BLOG_POSTS_FILE env var)",
+    ),
+    ("new_clear", "New / Clear"),
+    (
+        "new_clear_hover",
+        "Discard what's in the editor and start a new post",
+    ),
+    ("theme", "Theme:"),
+    ("language", "Language:"),
+    ("close_window", "Close the window"),
+    ("restore_window", "Restore window"),
+    ("maximize_window", "Maximize window"),
+    ("minimize_window", "Minimize the window"),
+    ("minimize_to_tray", "Minimize to tray"),
+    ("show_window", "Show window"),
+    ("quit", "Quit"),
+    ("density", "Density"),
+    ("compact", "Compact"),
+    ("comfortable", "Comfortable"),
+    ("changes", "Changes"),
+    ("no_changes", "No changes to the content"),
+    (
+        "no_http_update",
+        "Posts sent to an HTTP endpoint can't be updated from here",
+    ),
+    ("restore", "Restore"),
+    ("discard", "Discard"),
+    ("log", "Log"),
+    (
+        "logging_off",
+        "Logging is off, turn it on in the site settings",
+    ),
+    ("nothing_logged", "Nothing logged yet"),
+    ("passphrase", "Passphrase"),
+    ("unlock", "Unlock"),
+    ("cancel", "Cancel"),
+    ("dismiss", "Dismiss"),
+    ("copy_error", "Copy error"),
+    (
+        "changed_externally",
+        "The posts file was changed by another program.",
+    ),
+    (
+        "reload_keeps_editor",
+        "Reload it? What you have typed in the editor is kept.",
+    ),
+    ("reload", "Reload"),
+    ("not_now", "Not now"),
+    ("posts_file_reloaded", "Posts file reloaded"),
+    (
+        "only_json_drop",
+        "Only .json posts files can be dropped here",
+    ),
+    ("exporting_site", "Exporting site"),
+    ("text_files", "Text"),
+    ("importing_posts", "Importing posts"),
+    ("cancelled", "Cancelled"),
+    ("choose_blog", "Choose a blog"),
+    ("add_blog", "Add a blog"),
+    (
+        "switch_blogs_prompt",
+        "You have unsaved content. Switch blogs anyway?",
+    ),
+    ("switch", "Switch"),
+    ("recent_files", "Recent files"),
+    ("file", "File"),
+    ("choose_posts_file", "Choose posts file…"),
+    ("save", "Save"),
+    (
+        "save_hover",
+        "Save the site settings without publishing a post",
+    ),
+    ("show_in_file_manager", "Show in file manager"),
+    ("site_settings_menu", "Site settings…"),
+    ("stats_menu", "Stats…"),
+    ("view_log", "View log…"),
+    ("import_folder", "Import folder…"),
+    ("import_post", "Import post…"),
+    ("export_post", "Export post…"),
+    ("select_post_first", "Select a post to edit first"),
+    ("export_site", "Export site…"),
+    ("export_single_html", "Export single HTML file…"),
+    ("export_plain_text", "Export plain text…"),
+    ("open_exported_site", "Open exported site"),
+    ("export_site_first", "Export the site first"),
+    ("export_rss", "Export RSS feed"),
+    ("export_atom", "Export Atom feed"),
+    ("export_future_posts", "Export future-dated posts"),
+    ("no_posts_file", "No posts file selected"),
+    ("posts", "Posts"),
+    ("newest", "⬇ Newest"),
+    ("oldest", "⬆ Oldest"),
+    (
+        "toggle_order_hover",
+        "Toggle the order posts are listed and exported in",
+    ),
+    ("search", "Search"),
+    ("delete_post_hover", "Delete this post"),
+    ("copy_markdown", "Copy as Markdown"),
+    ("draft_badge", "DRAFT"),
+    ("mark_draft", "Mark as draft"),
+    ("mark_published", "Mark as published"),
+    ("duplicate", "Duplicate"),
+    (
+        "no_versions",
+        "No earlier versions yet. One is kept each time the post is updated.",
+    ),
+    ("choose_version", "Choose a version to see it"),
+    (
+        "restore_version_hover",
+        "Make this the current version, keeping the current one in the history",
+    ),
+    (
+        "update_or_discard_first",
+        "Update or discard your changes first",
+    ),
+    ("version_restored", "Version restored"),
+    ("go_to", "Go to"),
+    ("previous_page", "Previous page"),
+    ("next_page", "Next page"),
+    ("per_page", " per page"),
+    ("never", "never"),
+    ("spelling", "Spelling"),
+    ("spelling_dictionary", "Spelling dictionary"),
+    ("overused_words", "Overused words"),
+    (
+        "overused_hover",
+        "Highlight words used more often than the limit",
+    ),
+    ("more_than", "More than"),
+    ("times", "times"),
+    ("never_highlight", "Never highlight:"),
+    ("reset_to_default", "Reset to default"),
+    ("overused_settings", "Overused word settings"),
+    ("no_suggestions", "No suggestions"),
+    ("tab_indents", "Tab indents"),
+    (
+        "tab_indents_hover",
+        "Tab and Shift+Tab indent and dedent instead of moving focus",
+    ),
+    ("bold", "Bold"),
+    ("italic", "Italic"),
+    ("heading", "Heading"),
+    ("insert_image", "Insert image"),
+    (
+        "images_need_posts_file",
+        "Choose a posts file first, images are kept beside it",
+    ),
+    ("insert_date", "Insert the current date and time"),
+    ("insert_emoji", "Insert emoji"),
+    ("no_matching_emoji", "No matching emoji"),
+    ("change_date", "Change date"),
+    ("set_date", "Set date"),
+    (
+        "set_date_hover",
+        "Back- or future-date the post instead of using the current time",
+    ),
+    ("editor_font", "Editor font"),
+    ("fonts", "Fonts"),
+    ("editor_font_size", "Editor font size"),
+    ("leave_zen", "Press F11 or Esc to leave zen mode"),
+    ("find", "Find:"),
+    ("replace_with", "Replace with:"),
+    ("match_case", "Match case"),
+    ("replace_all", "Replace all"),
+    ("close", "Close"),
+    ("images", "Images"),
+    ("publish_this_post", "Publish this post?"),
+    ("always_show_plan", "Always show this before publishing"),
+    (
+        "discard_prompt",
+        "You have unsaved content. Discard it and start a new post?",
+    ),
+    ("close_prompt", "You have unsaved content. Close anyway?"),
+    ("saved", "Saved"),
+    ("update_successful", "Update successful"),
+    ("publish_successful", "Publish successful"),
+    ("encryption", "Encryption"),
+    (
+        "file_is_encrypted",
+        "The posts file is encrypted with a passphrase.",
+    ),
+    ("remove_encryption", "Remove encryption"),
+    ("encrypt", "Encrypt"),
+    (
+        "encrypt_hover",
+        "There is no way to recover the posts without it",
+    ),
+    ("posts_file_encrypted", "Posts file encrypted"),
+    ("posts_file_decrypted", "Posts file decrypted"),
+    ("stats", "Stats"),
+    ("per_month", "Per month"),
+    ("tags_heading", "Tags"),
+    ("site_settings", "Site settings"),
+    ("page", "Page"),
+    ("title", "Title"),
+    ("timestamp_format", "Timestamp format"),
+    (
+        "timestamp_format_hover",
+        "A chrono strftime format, e.g. %Y-%m-%d %H:%M",
+    ),
+    ("limit_posts_to", "Limit posts to"),
+    ("words_suffix", " words"),
+    ("keep", "Keep"),
+    ("earlier_versions", "earlier versions of each post"),
+    ("backups", "backups of the posts file"),
+    ("html_template", "HTML template"),
+    (
+        "template_hover",
+        "Placeholders: {{title}}, {{content}}, {{footer}}, {{css}}, {{site_title}}",
+    ),
+    ("built_in", "Built-in"),
+    ("choose", "Choose…"),
+    ("footer_links", "Footer links"),
+    ("drag_to_reorder", "Drag to reorder"),
+    ("label", "Label"),
+    ("delete_link_hover", "Delete this link"),
+    ("add_link", "Add link"),
+    ("footer_links_saved", "Footer links saved"),
+    ("publishing", "Publishing"),
+    ("warn_larger_than", "Warn when a post is larger than"),
+    (
+        "show_changes_before_publish",
+        "Show what will change before publishing",
+    ),
+    ("pretty_json", "Pretty-print the posts file"),
+    (
+        "pretty_json_hover",
+        "Easier to read and to diff under version control",
+    ),
+    ("autolink", "Autolink on publish"),
+    (
+        "autolink_hover",
+        "Save bare URLs in posts as links, as the preview shows them",
+    ),
+    ("log_actions", "Log publishes, updates and deletes"),
+    ("log_actions_hover", "To blog.log beside the posts file"),
+    ("date_titles", "Start new titles with the date"),
+    ("date_format_hover", "A chrono format, like %Y-%m-%d"),
+    ("publish_to", "Publish to"),
+    ("posts_file_target", "Posts file"),
+    ("http_endpoint", "HTTP endpoint"),
+    (
+        "http_endpoint_hover",
+        "Posts are sent here as JSON instead of written to the posts file",
+    ),
+    (
+        "new_site_prompt",
+        "This is a new posts file. What should the page be called?",
+    ),
+    ("create", "Create"),
+    ("delete", "Delete"),
+    ("delete_successful", "Delete successful"),
+    ("history_hover", "Earlier versions of this post"),
+    ("undo_last_action", "Undo last action"),
+    ("action_publish", "publish"),
+    ("action_update", "update"),
+    ("action_delete", "delete"),
+    ("restore_draft_prompt", "Restore the autosaved draft '{}'?"),
+    (
+        "cant_undo",
+        "The posts have changed since the {}, so it can't be undone",
+    ),
+    ("undid", "Undid the {}"),
+    ("undo_action", "Undo the {}"),
+    ("file_encrypted", "{} is encrypted."),
+    ("exported_archive", "Exported archive to {}"),
+    ("exported_text", "Exported text to {}"),
+    ("could_not_open", "Could not open {}"),
+    ("stopped_unexpectedly", "{} stopped unexpectedly"),
+    ("imported_posts", "Imported {} posts"),
+    ("exported_files", "Exported {} files to {}"),
+    ("imported_post_as", "Imported post as {}"),
+    ("exported_post", "Exported post to {}"),
+    ("wrote_feed", "Wrote feed to {}"),
+    (
+        "could_not_open_file_manager",
+        "Could not open the file manager: {}",
+    ),
+    ("footer_link_warning", "⚠ Footer link {}: {}"),
+    ("copied_as_markdown", "Copied \"{}\" as Markdown"),
+    ("history_of", "History of {}"),
+    ("replaced_at", "Replaced {}\n{}"),
+    ("title_copy", "{} (copy)"),
+    ("no_posts_before", "No posts on or before {}"),
+    ("post_count_one", "{} post • last published:"),
+    ("post_count_many", "{} posts • last published:"),
+    ("add_to_dictionary", "Add \"{}\" to dictionary"),
+    (
+        "could_not_add_word",
+        "Could not add \"{}\" to the dictionary: {}",
+    ),
+    ("content_stats", "{} words • {} characters • {} min read"),
+    ("words_over_limit", "{} words over the limit of {}"),
+    ("words_left", "• {} words left"),
+    ("could_not_load_font", "Could not load font {}: {}"),
+    ("could_not_add_tray", "Could not add the tray icon: {}"),
+    (
+        "duplicate_title",
+        "There is already a post titled \"{}\". Publish anyway?",
+    ),
+    ("will_be_created", "{} will be created."),
+    (
+        "publish_plan_summary",
+        "\"{}\" will be dated {} and exported as {}.html.",
+    ),
+    ("blog_will_have", "The blog will have {} posts."),
+    (
+        "size_warning",
+        "{} - but this post is {} KB, over the {} KB warning size",
+    ),
+    ("total_posts", "{} posts"),
+    ("save_settings_hover", "Save the page and footer links ({})"),
+    (
+        "delete_prompt",
+        "Delete '{}'? It can be brought back with \"Undo last action\"",
+    ),
+    ("history_count", "History ({})"),
+    ("site_url", "Site URL"),
+    (
+        "site_url_hover",
+        "Where the exported site is hosted, linked from the RSS feed",
+    ),
+    ("indent_spaces", "{} spaces"),
+    ("indent_tab", "Tab"),
+    ("font_proportional", "Proportional"),
+    ("font_monospace", "Monospace"),
+    ("font_custom", "Custom…"),
+    ("zoom_shortcut", "Ctrl+Plus / Ctrl+Minus"),
+    ("css", "CSS"),
+];
+
+const FRENCH: &[(&str, &str)] = &[
+    ("app_title", "Wizard Of Arc – Rédaction de billets"),
+    ("saving", "Enregistrement…"),
+    ("enter_title", "Titre"),
+    ("enter_content", "Contenu"),
+    ("preview", "Aperçu"),
+    ("wrap", "Retour à la ligne"),
+    ("line_numbers", "Numéros de ligne"),
+    ("zen", "Zen"),
+    ("zen_hover", "Écriture sans distraction (F11)"),
+    ("tags", "Étiquettes :"),
+    ("tags_hint", "séparées, par des virgules"),
+    ("published", "Publié"),
+    (
+        "published_hover",
+        "Les billets non publiés sont exclus du site exporté et des flux",
+    ),
+    ("update", "Mettre à jour"),
+    ("publish", "Publier"),
+    (
+        "choose_posts_file_first",
+        "Choisissez d'abord un fichier de billets (ou définissez la variable BLOG_POSTS_FILE)",
+    ),
+    ("new_clear", "Nouveau / Effacer"),
+    (
+        "new_clear_hover",
+        "Abandonner le contenu de l'éditeur et commencer un nouveau billet",
+    ),
+    ("theme", "Thème :"),
+    ("language", "Langue :"),
+    ("close_window", "Fermer la fenêtre"),
+    ("restore_window", "Restaurer la fenêtre"),
+    ("maximize_window", "Agrandir la fenêtre"),
+    ("minimize_window", "Réduire la fenêtre"),
+    ("minimize_to_tray", "Réduire dans la zone de notification"),
+    ("show_window", "Afficher la fenêtre"),
+    ("quit", "Quitter"),
+    ("density", "Densité"),
+    ("compact", "Compacte"),
+    ("comfortable", "Confortable"),
+    ("changes", "Modifications"),
+    ("no_changes", "Aucune modification du contenu"),
+    (
+        "no_http_update",
+        "Les billets envoyés à un point d'accès HTTP ne peuvent pas être modifiés ici",
+    ),
+    ("restore", "Restaurer"),
+    ("discard", "Abandonner"),
+    ("log", "Journal"),
+    (
+        "logging_off",
+        "La journalisation est désactivée, activez-la dans les réglages du site",
+    ),
+    ("nothing_logged", "Rien n'a encore été journalisé"),
+    ("passphrase", "Phrase secrète"),
+    ("unlock", "Déverrouiller"),
+    ("cancel", "Annuler"),
+    ("dismiss", "Fermer"),
+    ("copy_error", "Copier l'erreur"),
+    (
+        "changed_externally",
+        "Le fichier des billets a été modifié par un autre programme.",
+    ),
+    (
+        "reload_keeps_editor",
+        "Le recharger ? Ce que vous avez saisi dans l'éditeur est conservé.",
+    ),
+    ("reload", "Recharger"),
+    ("not_now", "Pas maintenant"),
+    ("posts_file_reloaded", "Fichier des billets rechargé"),
+    (
+        "only_json_drop",
+        "Seuls les fichiers de billets .json peuvent être déposés ici",
+    ),
+    ("exporting_site", "Export du site"),
+    ("text_files", "Texte"),
+    ("importing_posts", "Import des billets"),
+    ("cancelled", "Annulé"),
+    ("choose_blog", "Choisir un blog"),
+    ("add_blog", "Ajouter un blog"),
+    (
+        "switch_blogs_prompt",
+        "Vous avez du contenu non enregistré. Changer de blog quand même ?",
+    ),
+    ("switch", "Changer"),
+    ("recent_files", "Fichiers récents"),
+    ("file", "Fichier"),
+    ("choose_posts_file", "Choisir le fichier des billets…"),
+    ("save", "Enregistrer"),
+    (
+        "save_hover",
+        "Enregistrer les réglages du site sans publier de billet",
+    ),
+    (
+        "show_in_file_manager",
+        "Afficher dans le gestionnaire de fichiers",
+    ),
+    ("site_settings_menu", "Réglages du site…"),
+    ("stats_menu", "Statistiques…"),
+    ("view_log", "Voir le journal…"),
+    ("import_folder", "Importer un dossier…"),
+    ("import_post", "Importer un billet…"),
+    ("export_post", "Exporter le billet…"),
+    (
+        "select_post_first",
+        "Sélectionnez d'abord un billet à modifier",
+    ),
+    ("export_site", "Exporter le site…"),
+    ("export_single_html", "Exporter en un seul fichier HTML…"),
+    ("export_plain_text", "Exporter en texte brut…"),
+    ("open_exported_site", "Ouvrir le site exporté"),
+    ("export_site_first", "Exportez d'abord le site"),
+    ("export_rss", "Exporter le flux RSS"),
+    ("export_atom", "Exporter le flux Atom"),
+    (
+        "export_future_posts",
+        "Exporter les billets datés dans le futur",
+    ),
+    ("no_posts_file", "Aucun fichier de billets choisi"),
+    ("posts", "Billets"),
+    ("newest", "⬇ Récents"),
+    ("oldest", "⬆ Anciens"),
+    (
+        "toggle_order_hover",
+        "Inverser l'ordre dans lequel les billets sont listés et exportés",
+    ),
+    ("search", "Rechercher"),
+    ("delete_post_hover", "Supprimer ce billet"),
+    ("copy_markdown", "Copier en Markdown"),
+    ("draft_badge", "BROUILLON"),
+    ("mark_draft", "Marquer comme brouillon"),
+    ("mark_published", "Marquer comme publié"),
+    ("duplicate", "Dupliquer"),
+    (
+        "no_versions",
+        "Pas encore de version antérieure. Une version est conservée à chaque mise à jour du billet.",
+    ),
+    ("choose_version", "Choisissez une version pour l'afficher"),
+    (
+        "restore_version_hover",
+        "Faire de cette version la version actuelle, en gardant l'actuelle dans l'historique",
+    ),
+    (
+        "update_or_discard_first",
+        "Mettez d'abord à jour ou abandonnez vos modifications",
+    ),
+    ("version_restored", "Version restaurée"),
+    ("go_to", "Aller au"),
+    ("previous_page", "Page précédente"),
+    ("next_page", "Page suivante"),
+    ("per_page", " par page"),
+    ("never", "jamais"),
+    ("spelling", "Orthographe"),
+    ("spelling_dictionary", "Dictionnaire orthographique"),
+    ("overused_words", "Mots trop fréquents"),
+    (
+        "overused_hover",
+        "Surligner les mots employés plus souvent que la limite",
+    ),
+    ("more_than", "Plus de"),
+    ("times", "fois"),
+    ("never_highlight", "Ne jamais surligner :"),
+    ("reset_to_default", "Rétablir la valeur par défaut"),
+    ("overused_settings", "Réglages des mots trop fréquents"),
+    ("no_suggestions", "Aucune suggestion"),
+    ("tab_indents", "Tab indente"),
+    (
+        "tab_indents_hover",
+        "Tab et Maj+Tab indentent et désindentent au lieu de déplacer le focus",
+    ),
+    ("bold", "Gras"),
+    ("italic", "Italique"),
+    ("heading", "Intertitre"),
+    ("insert_image", "Insérer une image"),
+    (
+        "images_need_posts_file",
+        "Choisissez d'abord un fichier de billets, les images sont rangées à côté",
+    ),
+    ("insert_date", "Insérer la date et l'heure actuelles"),
+    ("insert_emoji", "Insérer un emoji"),
+    ("no_matching_emoji", "Aucun emoji correspondant"),
+    ("change_date", "Changer la date"),
+    ("set_date", "Choisir la date"),
+    (
+        "set_date_hover",
+        "Antidater ou postdater le billet au lieu d'utiliser l'heure actuelle",
+    ),
+    ("editor_font", "Police de l'éditeur"),
+    ("fonts", "Polices"),
+    ("editor_font_size", "Taille de police de l'éditeur"),
+    (
+        "leave_zen",
+        "Appuyez sur F11 ou Échap pour quitter le mode zen",
+    ),
+    ("find", "Rechercher :"),
+    ("replace_with", "Remplacer par :"),
+    ("match_case", "Respecter la casse"),
+    ("replace_all", "Tout remplacer"),
+    ("close", "Fermer"),
+    ("images", "Images"),
+    ("publish_this_post", "Publier ce billet ?"),
+    (
+        "always_show_plan",
+        "Toujours afficher ceci avant de publier",
+    ),
+    (
+        "discard_prompt",
+        "Vous avez du contenu non enregistré. L'abandonner et commencer un nouveau billet ?",
+    ),
+    (
+        "close_prompt",
+        "Vous avez du contenu non enregistré. Fermer quand même ?",
+    ),
+    ("saved", "Enregistré"),
+    ("update_successful", "Mise à jour réussie"),
+    ("publish_successful", "Publication réussie"),
+    ("encryption", "Chiffrement"),
+    (
+        "file_is_encrypted",
+        "Le fichier des billets est chiffré avec une phrase secrète.",
+    ),
+    ("remove_encryption", "Retirer le chiffrement"),
+    ("encrypt", "Chiffrer"),
+    (
+        "encrypt_hover",
+        "Il est impossible de récupérer les billets sans elle",
+    ),
+    ("posts_file_encrypted", "Fichier des billets chiffré"),
+    ("posts_file_decrypted", "Fichier des billets déchiffré"),
+    ("stats", "Statistiques"),
+    ("per_month", "Par mois"),
+    ("tags_heading", "Étiquettes"),
+    ("site_settings", "Réglages du site"),
+    ("page", "Page"),
+    ("title", "Titre"),
+    ("timestamp_format", "Format de l'horodatage"),
+    (
+        "timestamp_format_hover",
+        "Un format strftime de chrono, p. ex. %Y-%m-%d %H:%M",
+    ),
+    ("limit_posts_to", "Limiter les billets à"),
+    ("words_suffix", " mots"),
+    ("keep", "Conserver"),
+    ("earlier_versions", "versions antérieures de chaque billet"),
+    ("backups", "sauvegardes du fichier des billets"),
+    ("html_template", "Modèle HTML"),
+    (
+        "template_hover",
+        "Espaces réservés : {{title}}, {{content}}, {{footer}}, {{css}}, {{site_title}}",
+    ),
+    ("built_in", "Intégré"),
+    ("choose", "Choisir…"),
+    ("footer_links", "Liens de pied de page"),
+    ("drag_to_reorder", "Glisser pour réordonner"),
+    ("label", "Libellé"),
+    ("delete_link_hover", "Supprimer ce lien"),
+    ("add_link", "Ajouter un lien"),
+    ("footer_links_saved", "Liens de pied de page enregistrés"),
+    ("publishing", "Publication"),
+    ("warn_larger_than", "Avertir quand un billet dépasse"),
+    (
+        "show_changes_before_publish",
+        "Montrer ce qui va changer avant de publier",
+    ),
+    ("pretty_json", "Indenter le fichier des billets"),
+    (
+        "pretty_json_hover",
+        "Plus facile à lire et à comparer sous contrôle de version",
+    ),
+    ("autolink", "Lier automatiquement à la publication"),
+    (
+        "autolink_hover",
+        "Enregistrer les URL nues des billets comme liens, tels que l'aperçu les montre",
+    ),
+    (
+        "log_actions",
+        "Journaliser les publications, mises à jour et suppressions",
+    ),
+    (
+        "log_actions_hover",
+        "Dans blog.log, à côté du fichier des billets",
+    ),
+    ("date_titles", "Commencer les nouveaux titres par la date"),
+    ("date_format_hover", "Un format chrono, comme %Y-%m-%d"),
+    ("publish_to", "Publier vers"),
+    ("posts_file_target", "Fichier des billets"),
+    ("http_endpoint", "Point d'accès HTTP"),
+    (
+        "http_endpoint_hover",
+        "Les billets sont envoyés ici en JSON au lieu d'être écrits dans le fichier des billets",
+    ),
+    (
+        "new_site_prompt",
+        "C'est un nouveau fichier de billets. Comment la page doit-elle s'appeler ?",
+    ),
+    ("create", "Créer"),
+    ("delete", "Supprimer"),
+    ("delete_successful", "Suppression réussie"),
+    ("history_hover", "Versions antérieures de ce billet"),
+    ("undo_last_action", "Annuler la dernière action"),
+    ("action_publish", "publication"),
+    ("action_update", "mise à jour"),
+    ("action_delete", "suppression"),
+    (
+        "restore_draft_prompt",
+        "Restaurer le brouillon enregistré automatiquement « {} » ?",
+    ),
+    (
+        "cant_undo",
+        "Les billets ont changé depuis la {}, elle ne peut donc pas être annulée",
+    ),
+    ("undid", "Annulation de la {} effectuée"),
+    ("undo_action", "Annuler la {}"),
+    ("file_encrypted", "{} est chiffré."),
+    ("exported_archive", "Archive exportée vers {}"),
+    ("exported_text", "Texte exporté vers {}"),
+    ("could_not_open", "Impossible d'ouvrir {}"),
+    (
+        "stopped_unexpectedly",
+        "{} s'est arrêté de façon inattendue",
+    ),
+    ("imported_posts", "{} billets importés"),
+    ("exported_files", "{} fichiers exportés vers {}"),
+    ("imported_post_as", "Billet importé sous {}"),
+    ("exported_post", "Billet exporté vers {}"),
+    ("wrote_feed", "Flux écrit dans {}"),
+    (
+        "could_not_open_file_manager",
+        "Impossible d'ouvrir le gestionnaire de fichiers : {}",
+    ),
+    ("footer_link_warning", "⚠ Lien de pied de page {} : {}"),
+    ("copied_as_markdown", "« {} » copié en Markdown"),
+    ("history_of", "Historique de {}"),
+    ("replaced_at", "Remplacée {}\n{}"),
+    ("title_copy", "{} (copie)"),
+    ("no_posts_before", "Aucun billet le {} ou avant"),
+    ("post_count_one", "{} billet • dernière publication :"),
+    ("post_count_many", "{} billets • dernière publication :"),
+    ("add_to_dictionary", "Ajouter « {} » au dictionnaire"),
+    (
+        "could_not_add_word",
+        "Impossible d'ajouter « {} » au dictionnaire : {}",
+    ),
+    (
+        "content_stats",
+        "{} mots • {} caractères • {} min de lecture",
+    ),
+    ("words_over_limit", "{} mots au-delà de la limite de {}"),
+    ("words_left", "• encore {} mots"),
+    (
+        "could_not_load_font",
+        "Impossible de charger la police {} : {}",
+    ),
+    (
+        "could_not_add_tray",
+        "Impossible d'ajouter l'icône de la zone de notification : {}",
+    ),
+    (
+        "duplicate_title",
+        "Il existe déjà un billet intitulé « {} ». Publier quand même ?",
+    ),
+    ("will_be_created", "{} sera créé."),
+    (
+        "publish_plan_summary",
+        "« {} » sera daté du {} et exporté sous {}.html.",
+    ),
+    ("blog_will_have", "Le blog comptera {} billets."),
+    (
+        "size_warning",
+        "{} - mais ce billet fait {} Ko, au-delà du seuil d'avertissement de {} Ko",
+    ),
+    ("total_posts", "{} billets"),
+    (
+        "save_settings_hover",
+        "Enregistrer la page et les liens de pied de page ({})",
+    ),
+    (
+        "delete_prompt",
+        "Supprimer « {} » ? Il peut être rétabli avec « Annuler la dernière action »",
+    ),
+    ("history_count", "Historique ({})"),
+    ("site_url", "URL du site"),
+    (
+        "site_url_hover",
+        "L'adresse où le site exporté est hébergé, reprise dans le flux RSS",
+    ),
+    ("indent_spaces", "{} espaces"),
+    ("indent_tab", "Tabulation"),
+    ("font_proportional", "Proportionnelle"),
+    ("font_monospace", "Chasse fixe"),
+    ("font_custom", "Personnalisée…"),
+    ("zoom_shortcut", "Ctrl+Plus / Ctrl+Moins"),
+    ("css", "CSS"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+    ("app_title", "Wizard Of Arc – Blogeinträge schreiben"),
+    ("saving", "Wird gespeichert…"),
+    ("enter_title", "Titel"),
+    ("enter_content", "Inhalt"),
+    ("preview", "Vorschau"),
+    ("wrap", "Umbrechen"),
+    ("line_numbers", "Zeilennummern"),
+    ("zen", "Zen"),
+    ("zen_hover", "Ablenkungsfreies Schreiben (F11)"),
+    ("tags", "Schlagwörter:"),
+    ("tags_hint", "durch Kommas getrennt"),
+    ("published", "Veröffentlicht"),
+    (
+        "published_hover",
+        "Unveröffentlichte Beiträge fehlen in der exportierten Seite und den Feeds",
+    ),
+    ("update", "Aktualisieren"),
+    ("publish", "Veröffentlichen"),
+    (
+        "choose_posts_file_first",
+        "Wähle zuerst eine Beitragsdatei (oder setze die Variable BLOG_POSTS_FILE)",
+    ),
+    ("new_clear", "Neu / Leeren"),
+    (
+        "new_clear_hover",
+        "Den Inhalt des Editors verwerfen und einen neuen Beitrag beginnen",
+    ),
+    ("theme", "Design:"),
+    ("language", "Sprache:"),
+    ("close_window", "Fenster schließen"),
+    ("restore_window", "Fenster wiederherstellen"),
+    ("maximize_window", "Fenster maximieren"),
+    ("minimize_window", "Fenster minimieren"),
+    ("minimize_to_tray", "In den Infobereich minimieren"),
+    ("show_window", "Fenster anzeigen"),
+    ("quit", "Beenden"),
+    ("density", "Dichte"),
+    ("compact", "Kompakt"),
+    ("comfortable", "Komfortabel"),
+    ("changes", "Änderungen"),
+    ("no_changes", "Keine Änderungen am Inhalt"),
+    (
+        "no_http_update",
+        "An einen HTTP-Endpunkt gesendete Beiträge können hier nicht aktualisiert werden",
+    ),
+    ("restore", "Wiederherstellen"),
+    ("discard", "Verwerfen"),
+    ("log", "Protokoll"),
+    (
+        "logging_off",
+        "Die Protokollierung ist aus, sie lässt sich in den Website-Einstellungen einschalten",
+    ),
+    ("nothing_logged", "Noch nichts protokolliert"),
+    ("passphrase", "Passphrase"),
+    ("unlock", "Entsperren"),
+    ("cancel", "Abbrechen"),
+    ("dismiss", "Schließen"),
+    ("copy_error", "Fehler kopieren"),
+    (
+        "changed_externally",
+        "Die Beitragsdatei wurde von einem anderen Programm geändert.",
+    ),
+    (
+        "reload_keeps_editor",
+        "Neu laden? Was im Editor steht, bleibt erhalten.",
+    ),
+    ("reload", "Neu laden"),
+    ("not_now", "Nicht jetzt"),
+    ("posts_file_reloaded", "Beitragsdatei neu geladen"),
+    (
+        "only_json_drop",
+        "Hier können nur .json-Beitragsdateien abgelegt werden",
+    ),
+    ("exporting_site", "Website wird exportiert"),
+    ("text_files", "Text"),
+    ("importing_posts", "Beiträge werden importiert"),
+    ("cancelled", "Abgebrochen"),
+    ("choose_blog", "Blog auswählen"),
+    ("add_blog", "Blog hinzufügen"),
+    (
+        "switch_blogs_prompt",
+        "Es gibt ungespeicherte Inhalte. Trotzdem den Blog wechseln?",
+    ),
+    ("switch", "Wechseln"),
+    ("recent_files", "Zuletzt geöffnet"),
+    ("file", "Datei"),
+    ("choose_posts_file", "Beitragsdatei wählen…"),
+    ("save", "Speichern"),
+    (
+        "save_hover",
+        "Die Website-Einstellungen speichern, ohne einen Beitrag zu veröffentlichen",
+    ),
+    ("show_in_file_manager", "Im Dateimanager zeigen"),
+    ("site_settings_menu", "Website-Einstellungen…"),
+    ("stats_menu", "Statistiken…"),
+    ("view_log", "Protokoll anzeigen…"),
+    ("import_folder", "Ordner importieren…"),
+    ("import_post", "Beitrag importieren…"),
+    ("export_post", "Beitrag exportieren…"),
+    (
+        "select_post_first",
+        "Zuerst einen Beitrag zum Bearbeiten auswählen",
+    ),
+    ("export_site", "Website exportieren…"),
+    ("export_single_html", "Als einzelne HTML-Datei exportieren…"),
+    ("export_plain_text", "Als reinen Text exportieren…"),
+    ("open_exported_site", "Exportierte Website öffnen"),
+    ("export_site_first", "Zuerst die Website exportieren"),
+    ("export_rss", "RSS-Feed exportieren"),
+    ("export_atom", "Atom-Feed exportieren"),
+    (
+        "export_future_posts",
+        "Zukünftig datierte Beiträge exportieren",
+    ),
+    ("no_posts_file", "Keine Beitragsdatei ausgewählt"),
+    ("posts", "Beiträge"),
+    ("newest", "⬇ Neueste"),
+    ("oldest", "⬆ Älteste"),
+    (
+        "toggle_order_hover",
+        "Die Reihenfolge umkehren, in der Beiträge aufgelistet und exportiert werden",
+    ),
+    ("search", "Suchen"),
+    ("delete_post_hover", "Diesen Beitrag löschen"),
+    ("copy_markdown", "Als Markdown kopieren"),
+    ("draft_badge", "ENTWURF"),
+    ("mark_draft", "Als Entwurf markieren"),
+    ("mark_published", "Als veröffentlicht markieren"),
+    ("duplicate", "Duplizieren"),
+    (
+        "no_versions",
+        "Noch keine früheren Versionen. Bei jeder Aktualisierung des Beitrags wird eine aufbewahrt.",
+    ),
+    (
+        "choose_version",
+        "Eine Version auswählen, um sie anzuzeigen",
+    ),
+    (
+        "restore_version_hover",
+        "Diese Version zur aktuellen machen und die aktuelle im Verlauf behalten",
+    ),
+    (
+        "update_or_discard_first",
+        "Zuerst die Änderungen aktualisieren oder verwerfen",
+    ),
+    ("version_restored", "Version wiederhergestellt"),
+    ("go_to", "Gehe zu"),
+    ("previous_page", "Vorherige Seite"),
+    ("next_page", "Nächste Seite"),
+    ("per_page", " pro Seite"),
+    ("never", "nie"),
+    ("spelling", "Rechtschreibung"),
+    ("spelling_dictionary", "Wörterbuch"),
+    ("overused_words", "Häufige Wörter"),
+    (
+        "overused_hover",
+        "Wörter hervorheben, die öfter als erlaubt vorkommen",
+    ),
+    ("more_than", "Mehr als"),
+    ("times", "Mal"),
+    ("never_highlight", "Nie hervorheben:"),
+    ("reset_to_default", "Auf Standard zurücksetzen"),
+    ("overused_settings", "Einstellungen für häufige Wörter"),
+    ("no_suggestions", "Keine Vorschläge"),
+    ("tab_indents", "Tab rückt ein"),
+    (
+        "tab_indents_hover",
+        "Tab und Umschalt+Tab rücken ein und aus, statt den Fokus zu verschieben",
+    ),
+    ("bold", "Fett"),
+    ("italic", "Kursiv"),
+    ("heading", "Überschrift"),
+    ("insert_image", "Bild einfügen"),
+    (
+        "images_need_posts_file",
+        "Zuerst eine Beitragsdatei wählen, Bilder werden daneben abgelegt",
+    ),
+    ("insert_date", "Aktuelles Datum und Uhrzeit einfügen"),
+    ("insert_emoji", "Emoji einfügen"),
+    ("no_matching_emoji", "Kein passendes Emoji"),
+    ("change_date", "Datum ändern"),
+    ("set_date", "Datum festlegen"),
+    (
+        "set_date_hover",
+        "Den Beitrag vor- oder rückdatieren, statt die aktuelle Zeit zu verwenden",
+    ),
+    ("editor_font", "Editor-Schrift"),
+    ("fonts", "Schriften"),
+    ("editor_font_size", "Editor-Schriftgröße"),
+    ("leave_zen", "F11 oder Esc beendet den Zen-Modus"),
+    ("find", "Suchen:"),
+    ("replace_with", "Ersetzen durch:"),
+    ("match_case", "Groß-/Kleinschreibung beachten"),
+    ("replace_all", "Alle ersetzen"),
+    ("close", "Schließen"),
+    ("images", "Bilder"),
+    ("publish_this_post", "Diesen Beitrag veröffentlichen?"),
+    (
+        "always_show_plan",
+        "Dies immer vor dem Veröffentlichen anzeigen",
+    ),
+    (
+        "discard_prompt",
+        "Es gibt ungespeicherte Inhalte. Verwerfen und einen neuen Beitrag beginnen?",
+    ),
+    (
+        "close_prompt",
+        "Es gibt ungespeicherte Inhalte. Trotzdem schließen?",
+    ),
+    ("saved", "Gespeichert"),
+    ("update_successful", "Aktualisierung erfolgreich"),
+    ("publish_successful", "Veröffentlichung erfolgreich"),
+    ("encryption", "Verschlüsselung"),
+    (
+        "file_is_encrypted",
+        "Die Beitragsdatei ist mit einer Passphrase verschlüsselt.",
+    ),
+    ("remove_encryption", "Verschlüsselung entfernen"),
+    ("encrypt", "Verschlüsseln"),
+    (
+        "encrypt_hover",
+        "Ohne sie lassen sich die Beiträge nicht wiederherstellen",
+    ),
+    ("posts_file_encrypted", "Beitragsdatei verschlüsselt"),
+    ("posts_file_decrypted", "Beitragsdatei entschlüsselt"),
+    ("stats", "Statistiken"),
+    ("per_month", "Pro Monat"),
+    ("tags_heading", "Schlagwörter"),
+    ("site_settings", "Website-Einstellungen"),
+    ("page", "Seite"),
+    ("title", "Titel"),
+    ("timestamp_format", "Zeitstempelformat"),
+    (
+        "timestamp_format_hover",
+        "Ein strftime-Format von chrono, z. B. %Y-%m-%d %H:%M",
+    ),
+    ("limit_posts_to", "Beiträge begrenzen auf"),
+    ("words_suffix", " Wörter"),
+    ("keep", "Behalte"),
+    ("earlier_versions", "frühere Versionen jedes Beitrags"),
+    ("backups", "Sicherungen der Beitragsdatei"),
+    ("html_template", "HTML-Vorlage"),
+    (
+        "template_hover",
+        "Platzhalter: {{title}}, {{content}}, {{footer}}, {{css}}, {{site_title}}",
+    ),
+    ("built_in", "Eingebaut"),
+    ("choose", "Wählen…"),
+    ("footer_links", "Fußzeilen-Links"),
+    ("drag_to_reorder", "Zum Umsortieren ziehen"),
+    ("label", "Beschriftung"),
+    ("delete_link_hover", "Diesen Link löschen"),
+    ("add_link", "Link hinzufügen"),
+    ("footer_links_saved", "Fußzeilen-Links gespeichert"),
+    ("publishing", "Veröffentlichen"),
+    (
+        "warn_larger_than",
+        "Warnen, wenn ein Beitrag größer ist als",
+    ),
+    (
+        "show_changes_before_publish",
+        "Vor dem Veröffentlichen zeigen, was sich ändert",
+    ),
+    ("pretty_json", "Beitragsdatei lesbar formatieren"),
+    (
+        "pretty_json_hover",
+        "Leichter zu lesen und unter Versionskontrolle zu vergleichen",
+    ),
+    ("autolink", "Beim Veröffentlichen automatisch verlinken"),
+    (
+        "autolink_hover",
+        "Bloße URLs in Beiträgen als Links speichern, wie die Vorschau sie zeigt",
+    ),
+    (
+        "log_actions",
+        "Veröffentlichungen, Aktualisierungen und Löschungen protokollieren",
+    ),
+    ("log_actions_hover", "In blog.log neben der Beitragsdatei"),
+    ("date_titles", "Neue Titel mit dem Datum beginnen"),
+    ("date_format_hover", "Ein chrono-Format wie %Y-%m-%d"),
+    ("publish_to", "Veröffentlichen nach"),
+    ("posts_file_target", "Beitragsdatei"),
+    ("http_endpoint", "HTTP-Endpunkt"),
+    (
+        "http_endpoint_hover",
+        "Beiträge werden als JSON hierher gesendet, statt in die Beitragsdatei geschrieben",
+    ),
+    (
+        "new_site_prompt",
+        "Das ist eine neue Beitragsdatei. Wie soll die Seite heißen?",
+    ),
+    ("create", "Erstellen"),
+    ("delete", "Löschen"),
+    ("delete_successful", "Löschen erfolgreich"),
+    ("history_hover", "Frühere Versionen dieses Beitrags"),
+    ("undo_last_action", "Letzte Aktion rückgängig machen"),
+    ("action_publish", "Veröffentlichung"),
+    ("action_update", "Aktualisierung"),
+    ("action_delete", "Löschung"),
+    (
+        "restore_draft_prompt",
+        "Den automatisch gespeicherten Entwurf „{}“ wiederherstellen?",
+    ),
+    (
+        "cant_undo",
+        "Die Beiträge haben sich seit der {} geändert, sie kann daher nicht rückgängig gemacht werden",
+    ),
+    ("undid", "{} rückgängig gemacht"),
+    ("undo_action", "{} rückgängig machen"),
+    ("file_encrypted", "{} ist verschlüsselt."),
+    ("exported_archive", "Archiv nach {} exportiert"),
+    ("exported_text", "Text nach {} exportiert"),
+    ("could_not_open", "{} konnte nicht geöffnet werden"),
+    ("stopped_unexpectedly", "{} wurde unerwartet beendet"),
+    ("imported_posts", "{} Beiträge importiert"),
+    ("exported_files", "{} Dateien nach {} exportiert"),
+    ("imported_post_as", "Beitrag als {} importiert"),
+    ("exported_post", "Beitrag nach {} exportiert"),
+    ("wrote_feed", "Feed nach {} geschrieben"),
+    (
+        "could_not_open_file_manager",
+        "Der Dateimanager konnte nicht geöffnet werden: {}",
+    ),
+    ("footer_link_warning", "⚠ Fußzeilen-Link {}: {}"),
+    ("copied_as_markdown", "„{}“ als Markdown kopiert"),
+    ("history_of", "Verlauf von {}"),
+    ("replaced_at", "Ersetzt {}\n{}"),
+    ("title_copy", "{} (Kopie)"),
+    ("no_posts_before", "Keine Beiträge am oder vor dem {}"),
+    ("post_count_one", "{} Beitrag • zuletzt veröffentlicht:"),
+    ("post_count_many", "{} Beiträge • zuletzt veröffentlicht:"),
+    ("add_to_dictionary", "„{}“ zum Wörterbuch hinzufügen"),
+    (
+        "could_not_add_word",
+        "„{}“ konnte nicht zum Wörterbuch hinzugefügt werden: {}",
+    ),
+    ("content_stats", "{} Wörter • {} Zeichen • {} Min. Lesezeit"),
+    ("words_over_limit", "{} Wörter über der Grenze von {}"),
+    ("words_left", "• noch {} Wörter"),
+    (
+        "could_not_load_font",
+        "Schrift {} konnte nicht geladen werden: {}",
+    ),
+    (
+        "could_not_add_tray",
+        "Das Infobereich-Symbol konnte nicht hinzugefügt werden: {}",
+    ),
+    (
+        "duplicate_title",
+        "Es gibt bereits einen Beitrag mit dem Titel „{}“. Trotzdem veröffentlichen?",
+    ),
+    ("will_be_created", "{} wird erstellt."),
+    (
+        "publish_plan_summary",
+        "„{}“ wird auf {} datiert und als {}.html exportiert.",
+    ),
+    ("blog_will_have", "Der Blog wird {} Beiträge haben."),
+    (
+        "size_warning",
+        "{} - aber dieser Beitrag ist {} KB groß, über der Warngrenze von {} KB",
+    ),
+    ("total_posts", "{} Beiträge"),
+    (
+        "save_settings_hover",
+        "Seite und Fußzeilen-Links speichern ({})",
+    ),
+    (
+        "delete_prompt",
+        "„{}“ löschen? Er lässt sich mit „Letzte Aktion rückgängig machen“ zurückholen",
+    ),
+    ("history_count", "Verlauf ({})"),
+    ("site_url", "Website-URL"),
+    (
+        "site_url_hover",
+        "Wo die exportierte Website liegt, verlinkt im RSS-Feed",
+    ),
+    ("indent_spaces", "{} Leerzeichen"),
+    ("indent_tab", "Tabulator"),
+    ("font_proportional", "Proportional"),
+    ("font_monospace", "Festbreite"),
+    ("font_custom", "Eigene…"),
+    ("zoom_shortcut", "Strg+Plus / Strg+Minus"),
+    ("css", "CSS"),
+];
@@ -0,0 +1,11 @@
+//! The blog logic behind the Wizard Of Arc blog entry maker: reading and writing the posts
+//! file, publishing and editing posts, and exporting the blog as a site or feed.
+//!
+//! The desktop app in `main.rs` is one front-end on top of this.
+
+pub mod blog;
+
+pub use blog::{
+    BlogError, BlogPostsForJson, BlogStats, FooterLink, LineChange, Page, Post, PostForJson,
+    PostOrder, PublishPlan, ValidationWarning, load, publish, save,
+};
@@ -3,99 +3,3938 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 #![allow(rustdoc::missing_crate_level_docs)] // it's an example
 
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, mpsc};
+
+use azifirsteframe::blog;
+use chrono::{DateTime, Local};
+use clap::Parser;
 use eframe::egui::{self, ViewportCommand};
 use egui::{IconData, Vec2};
+use egui_commonmark::{CommonMarkCache, CommonMarkViewer};
+use serde::{Deserialize, Serialize};
+
+mod cli;
+mod config;
+mod css;
+mod density;
+mod draft;
+mod editor;
+mod emoji;
+mod fonts;
+mod history;
+mod i18n;
+mod overuse;
+mod spelling;
+mod tray;
+
+use config::Config;
+use draft::Draft;
+use fonts::EditorFont;
+use history::EditHistory;
+use spelling::SpellChecker;
+
+const DEFAULT_TITLE: &str = "Today's Entry";
+/// A chrono format for new posts' titles when they start with the date.
+const DEFAULT_DATE_TITLE_FORMAT: &str = "%Y-%m-%d — ";
+const DEFAULT_CONTENT: &str = "Something happend today";
+const MIN_INNER_SIZE: Vec2 = Vec2::new(800.0, 400.0);
+const WINDOW_KEY: &str = "window";
+const DRAFT_KEY: &str = "draft";
+const THEME_KEY: &str = "theme";
+const RECENT_FILES_KEY: &str = "recent_files";
+const MAX_RECENT_FILES: usize = 5;
+const POST_SIZE_WARNING_KEY: &str = "post_size_warning_kb";
+const DEFAULT_POST_SIZE_WARNING_KB: usize = 100;
+const EXPORT_FUTURE_POSTS_KEY: &str = "export_future_posts";
+const SOFT_WRAP_KEY: &str = "soft_wrap";
+const LINE_NUMBERS_KEY: &str = "line_numbers";
+const ZEN_MODE_KEY: &str = "zen_mode";
+const POSTS_PER_PAGE_KEY: &str = "posts_per_page";
+const DEFAULT_POSTS_PER_PAGE: usize = 20;
+const DEFAULT_MAX_WORDS: u32 = 280;
+const POST_EXCERPT_CHARS: usize = 80;
+const LAST_EXPORT_DIR_KEY: &str = "last_export_dir";
+const EDITOR_FONT_KEY: &str = "editor_font";
+const CUSTOM_FONT_KEY: &str = "custom_font";
+const EDITOR_FONT_SIZE_KEY: &str = "editor_font_size";
+const DEFAULT_EDITOR_FONT_SIZE: f32 = 14.0;
+const EDITOR_FONT_SIZES: std::ops::RangeInclusive<f32> = 8.0..=48.0;
+const PRETTY_JSON_KEY: &str = "pretty_json";
+const POST_ORDER_KEY: &str = "post_order";
+const AUTOLINK_ON_PUBLISH_KEY: &str = "autolink_on_publish";
+const PUBLISH_TARGET_KEY: &str = "publish_target";
+const SPELL_CHECK_KEY: &str = "spell_check";
+const SPELL_CHECK_LANGUAGE_KEY: &str = "spell_check_language";
+const LANGUAGE_KEY: &str = "language";
+const MINIMIZE_TO_TRAY_KEY: &str = "minimize_to_tray";
+const PREVIEW_SPLIT_KEY: &str = "preview_split";
+const DENSITY_KEY: &str = "density";
+const LOG_ACTIONS_KEY: &str = "log_actions";
+const HIGHLIGHT_OVERUSED_KEY: &str = "highlight_overused";
+const OVERUSE_THRESHOLD_KEY: &str = "overuse_threshold";
+const STOPWORDS_KEY: &str = "stopwords";
+const TAB_INDENTS_KEY: &str = "tab_indents";
+const INDENT_KEY: &str = "indent";
+const DATE_TITLES_KEY: &str = "date_titles";
+const DATE_TITLE_FORMAT_KEY: &str = "date_title_format";
+const CONFIRM_PUBLISH_KEY: &str = "confirm_publish";
+const BLOGS_KEY: &str = "blogs";
+const ACTIVE_BLOG_KEY: &str = "active_blog";
+const AUTOSAVE_INTERVAL_SECS: f64 = 10.0;
+const TOAST_SECS: f64 = 4.0;
+const CONTENT_EDITOR_ID: &str = "content_editor";
+const INSERTED_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+const PUBLISH_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Enter);
+const SAVE_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+const UNDO_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+const REDO_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Y);
+const REDO_SHIFT_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(
+    egui::Modifiers::COMMAND.plus(egui::Modifiers::SHIFT),
+    egui::Key::Z,
+);
+const ZEN_MODE_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::NONE, egui::Key::F11);
+const ZEN_MAX_WIDTH: f32 = 700.0;
+const EDITOR_HEIGHT: f32 = 200.0;
+/// The most the list of changes to a post grows to before it scrolls.
+const CHANGES_HEIGHT: f32 = 200.0;
+/// How much of the end of `blog.log` the log window shows.
+const LOG_TAIL_LINES: usize = 200;
+/// How often the open log window reads the log again, in seconds.
+const LOG_REFRESH_SECS: f32 = 1.0;
+/// The columns the plain-text export wraps posts to.
+const TEXT_EXPORT_WIDTH: usize = 72;
+/// How much of the width the editor gets next to the preview, until the divider is dragged.
+const DEFAULT_PREVIEW_SPLIT: f32 = 0.5;
+/// Neither the editor nor the preview can be dragged narrower than this.
+const MIN_PANE_WIDTH: f32 = 150.0;
+const SPLITTER_WIDTH: f32 = 6.0;
+const FIND_REPLACE_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::H);
+const FOCUS_CONTENT_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::L);
+// These take over egui's own zoom shortcuts, which would scale the whole window:
+const FONT_BIGGER_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Plus);
+// Ctrl+= too, so there's no need to hold Shift on most keyboards:
+const FONT_BIGGER_SECONDARY_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Equals);
+const FONT_SMALLER_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Minus);
+
+fn main() -> eframe::Result {
+    env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
+    if let Some(command) = cli::Cli::parse().command {
+        std::process::exit(cli::run(command));
+    }
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default()
+            .with_icon(IconData {
+                rgba: image::load_from_memory(include_bytes!("../assets/woa_logo_only.png"))
+                    .unwrap()
+                    .to_rgba8()
+                    .to_vec(),
+                width: 400,
+                height: 270,
+            })
+            .with_decorations(false) // Hide the OS-specific "chrome" around the window
+            .with_inner_size(MIN_INNER_SIZE)
+            .with_min_inner_size(MIN_INNER_SIZE)
+            .with_transparent(true), // To have rounded corners we need transparency
+        persist_window: false, // We restore the window ourselves in `MyApp::new`
+
+        ..Default::default()
+    };
+    eframe::run_native(
+        "Custom window frame", // unused title
+        options,
+        Box::new(|cc| Ok(Box::new(MyApp::new(cc)))),
+    )
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct WindowState {
+    inner_size: [f32; 2],
+    position: Option<[f32; 2]>,
+    maximized: bool,
+}
+
+impl WindowState {
+    fn restore(&self, ctx: &egui::Context) {
+        let inner_size = Vec2::from(self.inner_size).max(MIN_INNER_SIZE);
+        ctx.send_viewport_cmd(ViewportCommand::InnerSize(inner_size));
+        if let Some(position) = self.position {
+            ctx.send_viewport_cmd(ViewportCommand::OuterPosition(position.into()));
+        }
+        if self.maximized {
+            ctx.send_viewport_cmd(ViewportCommand::Maximized(true));
+        }
+    }
+
+    fn track(&mut self, ctx: &egui::Context) {
+        ctx.input(|i| {
+            let viewport = i.viewport();
+            self.maximized = viewport.maximized.unwrap_or(false);
+            // Keep the restored size rather than the maximized one:
+            if !self.maximized {
+                if let Some(rect) = viewport.inner_rect {
+                    self.inner_size = rect.size().into();
+                }
+                if let Some(rect) = viewport.outer_rect {
+                    self.position = Some(rect.min.into());
+                }
+            }
+        });
+    }
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self {
+            inner_size: MIN_INNER_SIZE.into(),
+            position: None,
+            maximized: false,
+        }
+    }
+}
+
+/// A publish or update being saved on a background thread.
+struct PendingSave {
+    receiver: mpsc::Receiver<(blog::BlogResult<bool>, blog::BlogPostsForJson)>,
+    /// What's being saved, for "Undo the publish".
+    action: &'static str,
+    message: &'static str,
+    size_kb: usize,
+    /// None when it's going to an HTTP endpoint without a posts file open.
+    posts_file: Option<PathBuf>,
+    /// The editor contents when it was submitted, so edits made during the save are kept.
+    submitted: Draft,
+}
+
+/// What a bulk import or export running on a background thread sends back.
+enum BulkUpdate {
+    Progress { done: usize, total: usize },
+    Imported(blog::BlogResult<usize>, Box<blog::BlogPostsForJson>),
+    Exported(blog::BlogResult<blog::ExportManifest>, PathBuf),
+}
+
+/// An import or export too long to run in the middle of a frame.
+struct BulkOperation {
+    label: &'static str,
+    receiver: mpsc::Receiver<BulkUpdate>,
+    /// Set to ask the worker to stop after the file it's on.
+    cancel: Arc<AtomicBool>,
+    done: usize,
+    total: usize,
+}
+
+impl BulkOperation {
+    /// Run `work` on a background thread, handing it a progress callback that reports back
+    /// here and breaks once cancelled.
+    fn start(
+        label: &'static str,
+        work: impl FnOnce(blog::Progress) -> BulkUpdate + Send + 'static,
+    ) -> BulkOperation {
+        let (sender, receiver) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let worker_cancel = cancel.clone();
+        std::thread::spawn(move || {
+            let progress_sender = sender.clone();
+            let update = work(&mut |done, total| {
+                let _ = progress_sender.send(BulkUpdate::Progress { done, total });
+                if worker_cancel.load(Ordering::Relaxed) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+            let _ = sender.send(update);
+        });
+        BulkOperation {
+            label,
+            receiver,
+            cancel,
+            done: 0,
+            total: 0,
+        }
+    }
+}
+
+/// The posts as they were before the last publish, update or delete, to go back to.
+struct UndoSnapshot {
+    action: &'static str,
+    before: blog::BlogPostsForJson,
+    /// The posts file's JSON straight after the action, so the undo can tell whether
+    /// anything else has changed since.
+    after_json: String,
+}
+
+struct MyApp {
+    result_text: String,
+    /// The `result_text` the toast is showing, and since when.
+    toast: (String, f64),
+    error_text: Option<String>,
+    title: String,
+    content: String,
+    tags: String,
+    published: bool,
+    scheduled_time: Option<DateTime<Local>>,
+    export_future_posts: bool,
+    last_export_dir: Option<PathBuf>,
+    blog_posts: blog::BlogPostsForJson,
+    search_query: String,
+    post_order: blog::PostOrder,
+    post_list_page: usize,
+    posts_per_page: usize,
+    /// The date last picked to jump to in the post list.
+    jump_date: chrono::NaiveDate,
+    /// The post the list should scroll to on its next frame.
+    scroll_to_post: Option<usize>,
+    selected_post: Option<usize>,
+    pending_delete: Option<usize>,
+    posts_file: Option<PathBuf>,
+    recent_files: Vec<PathBuf>,
+    blogs: Vec<PathBuf>,
+    active_blog: Option<usize>,
+    pending_blog_switch: Option<usize>,
+    window_state: WindowState,
+    show_preview: bool,
+    /// The editor's share of the width next to the preview.
+    preview_split: f32,
+    soft_wrap: bool,
+    show_line_numbers: bool,
+    zen_mode: bool,
+    editor_font: EditorFont,
+    custom_font: Option<PathBuf>,
+    editor_font_size: f32,
+    pretty_json: bool,
+    /// Store bare URLs as links when publishing, not just show them as links in the preview.
+    autolink_on_publish: bool,
+    /// New posts are titled with today's date in `date_title_format` instead of `DEFAULT_TITLE`.
+    date_titles: bool,
+    date_title_format: String,
+    publish_target: blog::PublishTarget,
+    spell_check: bool,
+    spell_check_language: String,
+    spell_checker: Option<SpellChecker>,
+    /// The misspelled word the content editor's context menu is open for, and its suggestions.
+    spelling_target: Option<(std::ops::Range<usize>, Vec<String>)>,
+    language: i18n::Language,
+    /// Whether the minimize button hides the window into the system tray.
+    minimize_to_tray: bool,
+    /// The tray icon, while `minimize_to_tray` is on.
+    tray: Option<tray::Tray>,
+    /// What `config.toml` was last read as or written with.
+    config: Config,
+    markdown_cache: CommonMarkCache,
+    title_invalid: bool,
+    content_invalid: bool,
+    footer_links: Vec<blog::FooterLink>,
+    page: blog::Page,
+    show_site_settings: bool,
+    show_stats: bool,
+    show_post_history: bool,
+    confirm_publish: bool,
+    pending_publish_plan: Option<blog::PublishPlan>,
+    /// The version picked in the history window, as an index into the post's history.
+    post_history_version: Option<usize>,
+    new_site_title: Option<String>,
+    autosaved_draft: Draft,
+    last_autosave: f64,
+    pending_draft_restore: Option<Draft>,
+    history: EditHistory,
+    show_reload_prompt: bool,
+    passphrase_prompt: Option<String>,
+    new_passphrase: String,
+    theme: egui::ThemePreference,
+    theme_applied: bool,
+    density: density::Density,
+    /// The density the style was last set to, to set it again when it changes.
+    applied_density: Option<density::Density>,
+    show_close_confirmation: bool,
+    close_confirmed: bool,
+    show_clear_confirmation: bool,
+    show_duplicate_title_confirmation: bool,
+    pending_save: Option<PendingSave>,
+    bulk_operation: Option<BulkOperation>,
+    undo: Option<UndoSnapshot>,
+    post_size_warning_kb: usize,
+    show_find_replace: bool,
+    find_text: String,
+    replace_text: String,
+    find_case_sensitive: bool,
+    emoji_search: String,
+    /// Write publishes, updates and deletes to `blog.log` beside the posts file.
+    log_actions: bool,
+    show_log: bool,
+    /// The end of the log as last read, and when, for the log window.
+    log_lines: Vec<String>,
+    log_read_at: Option<f64>,
+    /// Give words used more than `overuse_threshold` times a background in the editor.
+    highlight_overused: bool,
+    overuse_threshold: usize,
+    stopwords: String,
+    overuse_checker: overuse::OveruseChecker,
+    /// Tab and Shift+Tab indent and dedent in the content editor instead of moving focus.
+    tab_indents: bool,
+    indent: editor::Indent,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        let mut app = Self {
+            result_text: "".to_owned(),
+            toast: ("".to_owned(), 0.0),
+            error_text: None,
+            title: DEFAULT_TITLE.to_owned(),
+            content: DEFAULT_CONTENT.to_owned(),
+            tags: "".to_owned(),
+            published: true,
+            scheduled_time: None,
+            export_future_posts: false,
+            last_export_dir: None,
+            blog_posts: blog::BlogPostsForJson::default(),
+            search_query: "".to_owned(),
+            post_order: blog::PostOrder::NewestFirst,
+            post_list_page: 0,
+            jump_date: Local::now().date_naive(),
+            scroll_to_post: None,
+            posts_per_page: DEFAULT_POSTS_PER_PAGE,
+            selected_post: None,
+            pending_delete: None,
+            posts_file: blog::default_posts_file(),
+            recent_files: vec![],
+            blogs: vec![],
+            active_blog: None,
+            pending_blog_switch: None,
+            window_state: WindowState::default(),
+            show_preview: false,
+            preview_split: DEFAULT_PREVIEW_SPLIT,
+            soft_wrap: true,
+            show_line_numbers: false,
+            zen_mode: false,
+            editor_font: EditorFont::default(),
+            custom_font: None,
+            editor_font_size: DEFAULT_EDITOR_FONT_SIZE,
+            pretty_json: true,
+            autolink_on_publish: false,
+            date_titles: false,
+            date_title_format: DEFAULT_DATE_TITLE_FORMAT.to_owned(),
+            publish_target: blog::PublishTarget::default(),
+            spell_check: false,
+            spell_check_language: spelling::DEFAULT_LANGUAGE.to_owned(),
+            spell_checker: None,
+            spelling_target: None,
+            language: i18n::Language::from_system(),
+            minimize_to_tray: false,
+            tray: None,
+            config: Config::default(),
+            markdown_cache: CommonMarkCache::default(),
+            title_invalid: false,
+            content_invalid: false,
+            footer_links: vec![],
+            page: blog::Page::default(),
+            show_site_settings: false,
+            show_stats: false,
+            show_post_history: false,
+            confirm_publish: true,
+            pending_publish_plan: None,
+            post_history_version: None,
+            new_site_title: None,
+            autosaved_draft: Draft::default(),
+            last_autosave: 0.0,
+            pending_draft_restore: None,
+            history: EditHistory::default(),
+            show_reload_prompt: false,
+            passphrase_prompt: None,
+            new_passphrase: "".to_owned(),
+            theme: egui::ThemePreference::System,
+            theme_applied: false,
+            density: density::Density::default(),
+            applied_density: None,
+            show_close_confirmation: false,
+            close_confirmed: false,
+            show_clear_confirmation: false,
+            show_duplicate_title_confirmation: false,
+            pending_save: None,
+            bulk_operation: None,
+            undo: None,
+            post_size_warning_kb: DEFAULT_POST_SIZE_WARNING_KB,
+            show_find_replace: false,
+            find_text: "".to_owned(),
+            replace_text: "".to_owned(),
+            find_case_sensitive: false,
+            emoji_search: "".to_owned(),
+            log_actions: false,
+            show_log: false,
+            log_lines: vec![],
+            log_read_at: None,
+            highlight_overused: false,
+            overuse_threshold: overuse::DEFAULT_THRESHOLD,
+            stopwords: overuse::DEFAULT_STOPWORDS.to_owned(),
+            overuse_checker: overuse::OveruseChecker::default(),
+            tab_indents: false,
+            indent: editor::Indent::default(),
+        };
+        app.reload_posts();
+        app
+    }
+}
+
+impl MyApp {
+    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let mut app = Self::default();
+        let mut restored_draft = false;
+        if let Some(storage) = cc.storage {
+            if let Some(window_state) = eframe::get_value::<WindowState>(storage, WINDOW_KEY) {
+                window_state.restore(&cc.egui_ctx);
+                app.window_state = window_state;
+            }
+            if let Some(draft) = eframe::get_value::<Draft>(storage, DRAFT_KEY) {
+                app.title = draft.title;
+                app.content = draft.content;
+                app.tags = draft.tags;
+                restored_draft = true;
+            }
+            if let Some(theme) = eframe::get_value(storage, THEME_KEY) {
+                app.theme = theme;
+            }
+            if let Some(recent_files) = eframe::get_value(storage, RECENT_FILES_KEY) {
+                app.recent_files = recent_files;
+            }
+            if let Some(post_size_warning_kb) = eframe::get_value(storage, POST_SIZE_WARNING_KEY) {
+                app.post_size_warning_kb = post_size_warning_kb;
+            }
+            if let Some(export_future_posts) = eframe::get_value(storage, EXPORT_FUTURE_POSTS_KEY) {
+                app.export_future_posts = export_future_posts;
+            }
+            if let Some(soft_wrap) = eframe::get_value(storage, SOFT_WRAP_KEY) {
+                app.soft_wrap = soft_wrap;
+            }
+            if let Some(show_line_numbers) = eframe::get_value(storage, LINE_NUMBERS_KEY) {
+                app.show_line_numbers = show_line_numbers;
+            }
+            if let Some(zen_mode) = eframe::get_value(storage, ZEN_MODE_KEY) {
+                app.zen_mode = zen_mode;
+            }
+            if let Some(posts_per_page) = eframe::get_value(storage, POSTS_PER_PAGE_KEY) {
+                app.posts_per_page = usize::max(posts_per_page, 1);
+            }
+            app.last_export_dir = eframe::get_value(storage, LAST_EXPORT_DIR_KEY).flatten();
+            if let Some(editor_font) = eframe::get_value(storage, EDITOR_FONT_KEY) {
+                app.editor_font = editor_font;
+            }
+            if let Some(editor_font_size) = eframe::get_value::<f32>(storage, EDITOR_FONT_SIZE_KEY)
+            {
+                app.editor_font_size =
+                    editor_font_size.clamp(*EDITOR_FONT_SIZES.start(), *EDITOR_FONT_SIZES.end());
+            }
+            if let Some(custom_font) =
+                eframe::get_value::<Option<PathBuf>>(storage, CUSTOM_FONT_KEY).flatten()
+            {
+                app.set_custom_font(&cc.egui_ctx, custom_font);
+            }
+            if let Some(post_order) = eframe::get_value(storage, POST_ORDER_KEY) {
+                app.post_order = post_order;
+                app.blog_posts.set_order(post_order);
+            }
+            if let Some(pretty_json) = eframe::get_value(storage, PRETTY_JSON_KEY) {
+                app.pretty_json = pretty_json;
+            }
+            if let Some(autolink_on_publish) = eframe::get_value(storage, AUTOLINK_ON_PUBLISH_KEY) {
+                app.autolink_on_publish = autolink_on_publish;
+            }
+            if let Some(tab_indents) = eframe::get_value(storage, TAB_INDENTS_KEY) {
+                app.tab_indents = tab_indents;
+            }
+            if let Some(indent) = eframe::get_value(storage, INDENT_KEY) {
+                app.indent = indent;
+            }
+            if let Some(highlight) = eframe::get_value(storage, HIGHLIGHT_OVERUSED_KEY) {
+                app.highlight_overused = highlight;
+            }
+            if let Some(threshold) = eframe::get_value(storage, OVERUSE_THRESHOLD_KEY) {
+                app.overuse_threshold = threshold;
+            }
+            if let Some(stopwords) = eframe::get_value(storage, STOPWORDS_KEY) {
+                app.stopwords = stopwords;
+            }
+            if let Some(log_actions) = eframe::get_value(storage, LOG_ACTIONS_KEY) {
+                app.log_actions = log_actions;
+            }
+            if let Some(density) = eframe::get_value(storage, DENSITY_KEY) {
+                app.density = density;
+            }
+            if let Some(date_titles) = eframe::get_value(storage, DATE_TITLES_KEY) {
+                app.date_titles = date_titles;
+            }
+            if let Some(format) = eframe::get_value(storage, DATE_TITLE_FORMAT_KEY) {
+                app.date_title_format = format;
+            }
+            if let Some(publish_target) = eframe::get_value(storage, PUBLISH_TARGET_KEY) {
+                app.publish_target = publish_target;
+            }
+            if let Some(spell_check) = eframe::get_value(storage, SPELL_CHECK_KEY) {
+                app.spell_check = spell_check;
+            }
+            if let Some(language) = eframe::get_value(storage, SPELL_CHECK_LANGUAGE_KEY) {
+                app.spell_check_language = language;
+            }
+            if let Some(confirm_publish) = eframe::get_value(storage, CONFIRM_PUBLISH_KEY) {
+                app.confirm_publish = confirm_publish;
+            }
+            if let Some(language) = eframe::get_value(storage, LANGUAGE_KEY) {
+                app.language = language;
+            }
+            if let Some(preview_split) = eframe::get_value(storage, PREVIEW_SPLIT_KEY) {
+                app.preview_split = preview_split;
+            }
+            if let Some(minimize_to_tray) = eframe::get_value(storage, MINIMIZE_TO_TRAY_KEY) {
+                app.minimize_to_tray = minimize_to_tray;
+            }
+            if let Some(blogs) = eframe::get_value(storage, BLOGS_KEY) {
+                app.blogs = blogs;
+            }
+            let active_blog: Option<usize> = eframe::get_value(storage, ACTIVE_BLOG_KEY).flatten();
+            if let Some(path) = active_blog.and_then(|index| app.blogs.get(index)).cloned() {
+                app.open_posts_file(path);
+            }
+        }
+        // The date setting is only known now, but a restored title is kept:
+        if !restored_draft {
+            app.title = app.default_title();
+        }
+        let config = Config::load();
+        app.apply_config(&config);
+        app.config = config;
+        app.autosaved_draft = app.current_draft();
+        app.history.reset(&app.content);
+        if let Some(posts_file) = &app.posts_file
+            && let Some(draft) = Draft::load(posts_file)
+            && !draft.is_empty()
+            && draft != app.autosaved_draft
+        {
+            app.pending_draft_restore = Some(draft);
+        }
+        app
+    }
+
+    /// `config.toml` wins over the settings remembered from last time, except that the env
+    /// var still picks the posts file when it's set.
+    fn apply_config(&mut self, config: &Config) {
+        if let Some(theme) = config.theme {
+            self.theme = theme;
+        }
+        if let Some(soft_wrap) = config.soft_wrap {
+            self.soft_wrap = soft_wrap;
+        }
+        if let Some(line_numbers) = config.line_numbers {
+            self.show_line_numbers = line_numbers;
+        }
+        if let Some(editor_font) = config.editor_font
+            && (editor_font != EditorFont::Custom || self.custom_font.is_some())
+        {
+            self.editor_font = editor_font;
+        }
+        if let Some(size) = config.editor_font_size {
+            self.editor_font_size =
+                size.clamp(*EDITOR_FONT_SIZES.start(), *EDITOR_FONT_SIZES.end());
+        }
+        if blog::default_posts_file().is_none()
+            && let Some(posts_file) = &config.posts_file
+            && self.posts_file.as_ref() != Some(posts_file)
+        {
+            self.open_posts_file(posts_file.clone());
+        }
+    }
+
+    fn current_config(&self) -> Config {
+        Config {
+            posts_file: self.posts_file.clone(),
+            theme: Some(self.theme),
+            soft_wrap: Some(self.soft_wrap),
+            line_numbers: Some(self.show_line_numbers),
+            editor_font: Some(self.editor_font),
+            editor_font_size: Some(self.editor_font_size),
+        }
+    }
+
+    fn current_draft(&self) -> Draft {
+        Draft {
+            title: self.title.clone(),
+            content: self.content.clone(),
+            tags: self.tags.clone(),
+        }
+    }
+
+    fn autosave_draft(&mut self, ctx: &egui::Context) {
+        let Some(posts_file) = &self.posts_file else {
+            return;
+        };
+        // Don't clobber the draft on disk before the user decided whether to restore it:
+        if self.pending_draft_restore.is_some() {
+            return;
+        }
+        let draft = self.current_draft();
+        if draft == self.autosaved_draft {
+            return;
+        }
+        let now = ctx.input(|i| i.time);
+        let elapsed = now - self.last_autosave;
+        if elapsed < AUTOSAVE_INTERVAL_SECS {
+            ctx.request_repaint_after_secs((AUTOSAVE_INTERVAL_SECS - elapsed) as f32);
+            return;
+        }
+        // Drafts are plain text, so an encrypted blog doesn't get one:
+        if !draft.is_empty() && !self.blog_posts.is_encrypted() {
+            draft.save(posts_file);
+        }
+        self.autosaved_draft = draft;
+        self.last_autosave = now;
+    }
+
+    fn draft_restore_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(draft) = self.pending_draft_restore.take() else {
+            return;
+        };
+        let mut restore = false;
+        let mut discard = false;
+        egui::Modal::new(egui::Id::new("draft_restore")).show(ctx, |ui| {
+            ui.label(i18n::tr_args(
+                self.language,
+                "restore_draft_prompt",
+                &[&draft.title],
+            ));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button(t("restore")).clicked() {
+                    restore = true;
+                }
+                if ui.button(t("discard")).clicked() {
+                    discard = true;
+                }
+            });
+        });
+        if restore {
+            self.title = draft.title.clone();
+            self.content = draft.content.clone();
+            self.tags = draft.tags.clone();
+            self.autosaved_draft = draft;
+        } else if discard {
+            if let Some(posts_file) = &self.posts_file {
+                Draft::clear(posts_file);
+            }
+        } else {
+            self.pending_draft_restore = Some(draft);
+        }
+    }
+
+    fn reload_posts(&mut self) {
+        let result = match &self.posts_file {
+            Some(posts_file) => blog::load(posts_file),
+            None => Ok(blog::BlogPostsForJson::default()),
+        };
+        let blog_posts = match result {
+            Ok(blog_posts) => blog_posts,
+            Err(blog::BlogError::PassphraseRequired) => {
+                self.passphrase_prompt = Some("".to_string());
+                blog::BlogPostsForJson::default()
+            }
+            Err(e) => {
+                self.error_text = Some(e.to_string());
+                blog::BlogPostsForJson::default()
+            }
+        };
+        self.set_blog_posts(blog_posts);
+    }
+
+    fn set_blog_posts(&mut self, mut blog_posts: blog::BlogPostsForJson) {
+        blog_posts.set_pretty_json(self.pretty_json);
+        blog_posts.set_order(self.post_order);
+        self.blog_posts = blog_posts;
+        self.footer_links = self.blog_posts.footer_links().to_vec();
+        self.page = self.blog_posts.page().clone();
+        self.undo = None;
+    }
+
+    fn record_undo(&mut self, action: &'static str, before: blog::BlogPostsForJson) {
+        self.undo = self
+            .blog_posts
+            .to_json_string_pretty()
+            .ok()
+            .map(|after_json| UndoSnapshot {
+                action,
+                before,
+                after_json,
+            });
+    }
+
+    /// Add `action` on the post titled `title` to the log, if logging is on.
+    fn log_action(
+        &self,
+        posts_file: &Path,
+        action: &str,
+        title: &str,
+        error: Option<&blog::BlogError>,
+    ) {
+        if !self.log_actions {
+            return;
+        }
+        let action = blog::LoggedAction {
+            action,
+            title,
+            error: error.map(|e| e.to_string()),
+        };
+        // A log that can't be written doesn't stop the action:
+        if let Err(e) = blog::log_action(posts_file, &action) {
+            log::warn!("{}", e);
+        }
+    }
+
+    /// Show the tail of `blog.log`, read again every `LOG_REFRESH_SECS` while it's open.
+    fn log_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(posts_file) = self.posts_file.clone().filter(|_| self.show_log) else {
+            self.log_read_at = None;
+            return;
+        };
+        let now = ctx.input(|i| i.time);
+        if self
+            .log_read_at
+            .is_none_or(|read_at| now - read_at >= LOG_REFRESH_SECS as f64)
+        {
+            match blog::log_tail(&posts_file, LOG_TAIL_LINES) {
+                Ok(lines) => self.log_lines = lines,
+                Err(e) => self.log_lines = vec![e.to_string()],
+            }
+            self.log_read_at = Some(now);
+        }
+        ctx.request_repaint_after_secs(LOG_REFRESH_SECS);
+        egui::Window::new(t("log"))
+            .open(&mut self.show_log)
+            .default_size([500.0, 300.0])
+            .show(ctx, |ui| {
+                ui.weak(blog::log_file(&posts_file).display().to_string());
+                if !self.log_actions {
+                    ui.weak(t("logging_off"));
+                }
+                ui.separator();
+                egui::ScrollArea::both()
+                    .stick_to_bottom(true)
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        if self.log_lines.is_empty() {
+                            ui.weak(t("nothing_logged"));
+                        }
+                        for line in &self.log_lines {
+                            ui.monospace(line.replace('\t', "  "));
+                        }
+                    });
+            });
+    }
+
+    /// Go back to the posts as they were before the last publish, update or delete, unless
+    /// something else has changed them since.
+    fn undo_last_action(&mut self) {
+        if self.busy() {
+            return;
+        }
+        let (Some(undo), Some(posts_file)) = (self.undo.take(), self.posts_file.clone()) else {
+            return;
+        };
+        if self.blog_posts.to_json_string_pretty().ok() != Some(undo.after_json) {
+            self.error_text = Some(i18n::tr_args(
+                self.language,
+                "cant_undo",
+                &[&action_name(self.language, undo.action)],
+            ));
+            return;
+        }
+        match blog::restore_snapshot(undo.before, &mut self.blog_posts, &posts_file) {
+            Ok(_) => {
+                let restored = self.blog_posts.clone();
+                self.set_blog_posts(restored);
+                self.selected_post = None;
+                self.result_text = i18n::tr_args(
+                    self.language,
+                    "undid",
+                    &[&action_name(self.language, undo.action)],
+                );
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn passphrase_prompt_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let (Some(passphrase), Some(posts_file)) = (&mut self.passphrase_prompt, &self.posts_file)
+        else {
+            return;
+        };
+        let mut unlock = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("passphrase_prompt")).show(ctx, |ui| {
+            ui.label(i18n::tr_args(
+                self.language,
+                "file_encrypted",
+                &[&posts_file.display()],
+            ));
+            let response = ui.add(
+                egui::TextEdit::singleline(passphrase)
+                    .password(true)
+                    .hint_text(t("passphrase")),
+            );
+            unlock = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button(t("unlock")).clicked() {
+                    unlock = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if unlock {
+            match blog::load_encrypted(posts_file, passphrase) {
+                Ok(blog_posts) => {
+                    self.passphrase_prompt = None;
+                    self.error_text = None;
+                    self.set_blog_posts(blog_posts);
+                }
+                Err(e) => self.show_error(e),
+            }
+        } else if cancelled || modal.should_close() {
+            self.passphrase_prompt = None;
+        }
+    }
+
+    fn show_error(&mut self, error: blog::BlogError) {
+        if matches!(error, blog::BlogError::FileChangedExternally) {
+            self.show_reload_prompt = true;
+        }
+        self.error_text = Some(error.to_string());
+    }
+
+    fn error_banner_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(error_text) = &self.error_text else {
+            return;
+        };
+        let error_color = ui.visuals().error_fg_color;
+        let mut dismissed = false;
+        egui::Frame::new()
+            .fill(error_color.gamma_multiply(0.15))
+            .stroke(egui::Stroke::new(1.0, error_color))
+            .corner_radius(4)
+            .inner_margin(6)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.colored_label(error_color, error_text);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("✖").on_hover_text(t("dismiss")).clicked() {
+                            dismissed = true;
+                        }
+                        if ui.small_button(t("copy_error")).clicked() {
+                            ui.ctx().copy_text(error_text.clone());
+                        }
+                    });
+                });
+            });
+        if dismissed {
+            self.error_text = None;
+        }
+    }
+
+    /// Show `result_text` briefly at the bottom of the window, then clear it.
+    fn toast_ui(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        if self.result_text != self.toast.0 {
+            self.toast = (self.result_text.clone(), now);
+        }
+        if self.result_text.is_empty() {
+            return;
+        }
+        let shown_for = now - self.toast.1;
+        if shown_for > TOAST_SECS {
+            self.result_text.clear();
+            self.toast.0.clear();
+            return;
+        }
+        ctx.request_repaint_after_secs((TOAST_SECS - shown_for) as f32);
+        egui::Area::new(egui::Id::new("toast"))
+            .anchor(egui::Align2::CENTER_BOTTOM, Vec2::new(0.0, -40.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| ui.label(&self.result_text));
+            });
+    }
+
+    fn reload_prompt_ui(&mut self, ctx: &egui::Context) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        let busy = self.busy();
+        if !self.show_reload_prompt {
+            return;
+        }
+        let mut reload = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("reload_prompt")).show(ctx, |ui| {
+            ui.label(t("changed_externally"));
+            ui.label(t("reload_keeps_editor"));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!busy, egui::Button::new(t("reload")))
+                    .on_disabled_hover_text(t("saving"))
+                    .clicked()
+                {
+                    reload = true;
+                }
+                if ui.button(t("not_now")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if reload {
+            self.reload_posts();
+            // The selected index may point at a different post now:
+            self.selected_post = None;
+            self.result_text = t("posts_file_reloaded").to_string();
+        }
+        if reload || cancelled || modal.should_close() {
+            self.show_reload_prompt = false;
+        }
+    }
+
+    fn open_posts_file(&mut self, path: PathBuf) {
+        if self.busy() {
+            return;
+        }
+        self.result_text = "".to_string();
+        self.error_text = None;
+        self.recent_files.retain(|recent| *recent != path);
+        self.recent_files.insert(0, path.clone());
+        self.recent_files.truncate(MAX_RECENT_FILES);
+        self.active_blog = self.blogs.iter().position(|blog| *blog == path);
+        self.posts_file = Some(path);
+        self.selected_post = None;
+        self.reload_posts();
+    }
+
+    fn choose_posts_file(&mut self) {
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .pick_file()
+        {
+            self.open_posts_file(path);
+        }
+    }
+
+    fn handle_dropped_files(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let dropped = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .first()
+                .and_then(|file| file.path.clone())
+        });
+        let Some(path) = dropped else {
+            return;
+        };
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            self.open_posts_file(path);
+        } else {
+            self.error_text = Some(t("only_json_drop").to_string());
+        }
+    }
+
+    fn export_site(&mut self) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        if let Some(output_dir) = rfd::FileDialog::new().pick_folder() {
+            let blog_posts = self.blog_posts.clone();
+            let include_future = self.export_future_posts;
+            self.bulk_operation =
+                Some(BulkOperation::start(t("exporting_site"), move |progress| {
+                    let result = blog::export_html_with_progress(
+                        &output_dir,
+                        &blog_posts,
+                        &posts_file,
+                        include_future,
+                        progress,
+                    );
+                    BulkUpdate::Exported(result, output_dir)
+                }));
+        }
+    }
+
+    fn export_single_file(&mut self) {
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        let Some(output_path) = rfd::FileDialog::new()
+            .add_filter("HTML", &["html"])
+            .set_file_name("archive.html")
+            .save_file()
+        else {
+            return;
+        };
+        match blog::export_single_html(
+            &output_path,
+            &self.blog_posts,
+            &posts_file,
+            self.export_future_posts,
+        ) {
+            Ok(_) => {
+                self.result_text =
+                    i18n::tr_args(self.language, "exported_archive", &[&output_path.display()])
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn export_plain_text(&mut self) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(output_path) = rfd::FileDialog::new()
+            .add_filter(t("text_files"), &["txt"])
+            .set_file_name("posts.txt")
+            .save_file()
+        else {
+            return;
+        };
+        match blog::export_text(
+            &output_path,
+            &self.blog_posts,
+            TEXT_EXPORT_WIDTH,
+            self.export_future_posts,
+        ) {
+            Ok(_) => {
+                self.result_text =
+                    i18n::tr_args(self.language, "exported_text", &[&output_path.display()])
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn open_exported_site(&mut self, ctx: &egui::Context) {
+        let Some(index) = self
+            .last_export_dir
+            .as_ref()
+            .map(|dir| dir.join("index.html"))
+        else {
+            return;
+        };
+        match url::Url::from_file_path(&index) {
+            Ok(url) if index.exists() => ctx.open_url(egui::OpenUrl::new_tab(url)),
+            _ => {
+                self.error_text = Some(i18n::tr_args(
+                    self.language,
+                    "could_not_open",
+                    &[&index.display()],
+                ))
+            }
+        }
+    }
+
+    fn import_folder(&mut self) {
+        let t = |key| i18n::tr(self.language, key);
+        if self.busy() {
+            return;
+        }
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        if let Some(dir) = rfd::FileDialog::new().pick_folder() {
+            let mut blog_posts = self.blog_posts.clone();
+            self.bulk_operation = Some(BulkOperation::start(
+                t("importing_posts"),
+                move |progress| {
+                    let result = blog::import_dir_with_progress(
+                        &dir,
+                        &mut blog_posts,
+                        &posts_file,
+                        progress,
+                    );
+                    BulkUpdate::Imported(result, Box::new(blog_posts))
+                },
+            ));
+        }
+    }
+
+    /// Pick up the progress and, once it's done, the result of the running import or export.
+    fn poll_bulk_operation(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(operation) = &mut self.bulk_operation else {
+            return;
+        };
+        let finished = loop {
+            match operation.receiver.try_recv() {
+                Ok(BulkUpdate::Progress { done, total }) => {
+                    operation.done = done;
+                    operation.total = total;
+                }
+                Ok(update) => break Some(update),
+                Err(mpsc::TryRecvError::Empty) => break None,
+                // The worker panicked:
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.error_text = Some(i18n::tr_args(
+                        self.language,
+                        "stopped_unexpectedly",
+                        &[&operation.label],
+                    ));
+                    self.bulk_operation = None;
+                    return;
+                }
+            }
+        };
+        let Some(update) = finished else {
+            ctx.request_repaint_after_secs(0.1);
+            return;
+        };
+        self.bulk_operation = None;
+        match update {
+            BulkUpdate::Imported(Ok(count), blog_posts) => {
+                self.blog_posts = *blog_posts;
+                self.result_text = i18n::tr_args(self.language, "imported_posts", &[&count]);
+                // Imported posts are sorted in among the others, shifting indices:
+                self.selected_post = None;
+            }
+            BulkUpdate::Exported(Ok(manifest), output_dir) => {
+                self.result_text = i18n::tr_args(
+                    self.language,
+                    "exported_files",
+                    &[&manifest.files.len(), &output_dir.display()],
+                );
+                self.last_export_dir = Some(output_dir);
+            }
+            BulkUpdate::Imported(Err(blog::BlogError::Cancelled), _)
+            | BulkUpdate::Exported(Err(blog::BlogError::Cancelled), _) => {
+                self.result_text = t("cancelled").to_string();
+            }
+            BulkUpdate::Imported(Err(e), _) | BulkUpdate::Exported(Err(e), _) => self.show_error(e),
+            BulkUpdate::Progress { .. } => unreachable!("progress is handled above"),
+        }
+    }
+
+    fn bulk_operation_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(operation) = &self.bulk_operation else {
+            return;
+        };
+        egui::Modal::new(egui::Id::new("bulk_operation")).show(ctx, |ui| {
+            ui.label(operation.label);
+            let fraction = if operation.total == 0 {
+                0.0
+            } else {
+                operation.done as f32 / operation.total as f32
+            };
+            ui.add(
+                egui::ProgressBar::new(fraction)
+                    .desired_width(300.0)
+                    .text(format!("{} / {}", operation.done, operation.total)),
+            );
+            let cancelling = operation.cancel.load(Ordering::Relaxed);
+            if ui
+                .add_enabled(!cancelling, egui::Button::new(t("cancel")))
+                .clicked()
+            {
+                operation.cancel.store(true, Ordering::Relaxed);
+            }
+        });
+    }
+
+    fn import_post(&mut self) {
+        if self.busy() {
+            return;
+        }
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        match blog::import_post(&path, &mut self.blog_posts, &posts_file) {
+            Ok(slug) => {
+                self.result_text = i18n::tr_args(self.language, "imported_post_as", &[&slug]);
+                self.selected_post = None;
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn export_selected_post(&mut self) {
+        let Some(post) = self
+            .selected_post
+            .and_then(|index| self.blog_posts.posts().get(index))
+        else {
+            return;
+        };
+        let Some(output_path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .set_file_name(format!("{}.json", post.slug))
+            .save_file()
+        else {
+            return;
+        };
+        match blog::export_post(&output_path, post) {
+            Ok(_) => {
+                self.result_text =
+                    i18n::tr_args(self.language, "exported_post", &[&output_path.display()])
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn export_feed(
+        &mut self,
+        feed_name: &str,
+        export: fn(&Path, &blog::BlogPostsForJson, bool) -> blog::BlogResult<()>,
+    ) {
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        let feed_file = posts_file.with_file_name(feed_name);
+        match export(&feed_file, &self.blog_posts, self.export_future_posts) {
+            Ok(_) => {
+                self.result_text =
+                    i18n::tr_args(self.language, "wrote_feed", &[&feed_file.display()])
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn blog_switcher_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        let blog_name = |path: &PathBuf| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string())
+        };
+        let selected_text = self
+            .active_blog
+            .and_then(|index| self.blogs.get(index))
+            .map(blog_name)
+            .unwrap_or_else(|| t("choose_blog").to_string());
+        let mut chosen = None;
+        egui::ComboBox::from_id_salt("blog_switcher")
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for (index, path) in self.blogs.iter().enumerate() {
+                    if ui
+                        .selectable_label(self.active_blog == Some(index), blog_name(path))
+                        .on_hover_text(path.display().to_string())
+                        .clicked()
+                    {
+                        chosen = Some(index);
+                    }
+                }
+            });
+        if ui.small_button("➕").on_hover_text(t("add_blog")).clicked()
+            && let Some(path) = rfd::FileDialog::new()
+                .add_filter("JSON", &["json"])
+                .pick_file()
+        {
+            if !self.blogs.contains(&path) {
+                self.blogs.push(path.clone());
+            }
+            chosen = self.blogs.iter().position(|blog| *blog == path);
+        }
+        if let Some(index) = chosen.filter(|index| Some(*index) != self.active_blog) {
+            if self.has_unsaved_content() {
+                self.pending_blog_switch = Some(index);
+            } else {
+                self.switch_blog(index);
+            }
+        }
+    }
+
+    fn switch_blog(&mut self, index: usize) {
+        let Some(path) = self.blogs.get(index).cloned() else {
+            return;
+        };
+        self.title = "".to_string();
+        self.content = "".to_string();
+        self.tags = "".to_string();
+        self.published = true;
+        self.scheduled_time = None;
+        self.autosaved_draft = self.current_draft();
+        self.history.reset(&self.content);
+        self.open_posts_file(path);
+    }
+
+    fn blog_switch_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(index) = self.pending_blog_switch else {
+            return;
+        };
+        let mut switch = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("blog_switch_confirmation")).show(ctx, |ui| {
+            ui.label(t("switch_blogs_prompt"));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button(t("switch")).clicked() {
+                    switch = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if switch {
+            self.switch_blog(index);
+        }
+        if switch || cancelled || modal.should_close() {
+            self.pending_blog_switch = None;
+        }
+    }
+
+    fn recent_files_menu_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        self.recent_files.retain(|path| path.exists());
+        let mut chosen = None;
+        ui.add_enabled_ui(!self.recent_files.is_empty(), |ui| {
+            ui.menu_button(t("recent_files"), |ui| {
+                for path in &self.recent_files {
+                    if ui.button(path.display().to_string()).clicked() {
+                        chosen = Some(path.clone());
+                    }
+                }
+            });
+        });
+        if let Some(path) = chosen {
+            self.open_posts_file(path);
+        }
+    }
+
+    fn file_menu_ui(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        ui.add_enabled_ui(!self.busy(), |ui| {
+            ui.menu_button(t("file"), |ui| {
+                if ui.button(t("choose_posts_file")).clicked() {
+                    self.choose_posts_file();
+                }
+                self.recent_files_menu_ui(ui);
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("save"))
+                            .shortcut_text(ui.ctx().format_shortcut(&SAVE_SHORTCUT)),
+                    )
+                    .on_hover_text(t("save_hover"))
+                    .clicked()
+                {
+                    self.save_settings();
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("show_in_file_manager")),
+                    )
+                    .clicked()
+                    && let Some(posts_file) = &self.posts_file
+                    && let Err(e) = reveal_in_file_manager(posts_file)
+                {
+                    self.error_text = Some(i18n::tr_args(
+                        language,
+                        "could_not_open_file_manager",
+                        &[&e],
+                    ));
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("site_settings_menu")),
+                    )
+                    .clicked()
+                {
+                    self.show_site_settings = true;
+                }
+                if ui.button(t("stats_menu")).clicked() {
+                    self.show_stats = true;
+                }
+                if ui
+                    .add_enabled(self.posts_file.is_some(), egui::Button::new(t("view_log")))
+                    .clicked()
+                {
+                    self.show_log = true;
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("import_folder")),
+                    )
+                    .clicked()
+                {
+                    self.import_folder();
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("import_post")),
+                    )
+                    .clicked()
+                {
+                    self.import_post();
+                }
+                if ui
+                    .add_enabled(
+                        self.selected_post.is_some(),
+                        egui::Button::new(t("export_post")),
+                    )
+                    .on_disabled_hover_text(t("select_post_first"))
+                    .clicked()
+                {
+                    self.export_selected_post();
+                }
+                // Shown before exporting, but they don't stop it:
+                for warning in self.blog_posts.validate() {
+                    ui.colored_label(
+                        ui.visuals().warn_fg_color,
+                        i18n::tr_args(
+                            language,
+                            "footer_link_warning",
+                            &[&(warning.index + 1), &warning.message],
+                        ),
+                    );
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("export_site")),
+                    )
+                    .clicked()
+                {
+                    self.export_site();
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("export_single_html")),
+                    )
+                    .clicked()
+                {
+                    self.export_single_file();
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("export_plain_text")),
+                    )
+                    .clicked()
+                {
+                    self.export_plain_text();
+                }
+                if ui
+                    .add_enabled(
+                        self.last_export_dir.is_some(),
+                        egui::Button::new(t("open_exported_site")),
+                    )
+                    .on_disabled_hover_text(t("export_site_first"))
+                    .clicked()
+                {
+                    self.open_exported_site(ui.ctx());
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("export_rss")),
+                    )
+                    .clicked()
+                {
+                    self.export_feed("feed.xml", blog::export_rss);
+                }
+                if ui
+                    .add_enabled(
+                        self.posts_file.is_some(),
+                        egui::Button::new(t("export_atom")),
+                    )
+                    .clicked()
+                {
+                    self.export_feed("atom.xml", blog::export_atom);
+                }
+                ui.checkbox(&mut self.export_future_posts, t("export_future_posts"));
+            });
+        });
+    }
+
+    fn post_list_ui(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        let busy = self.busy();
+        self.file_menu_ui(ui);
+        let file_label = self
+            .posts_file
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| t("no_posts_file").to_string());
+        ui.label(file_label);
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.heading(t("posts"));
+            let (order_label, toggled) = match self.post_order {
+                blog::PostOrder::NewestFirst => (t("newest"), blog::PostOrder::OldestFirst),
+                blog::PostOrder::OldestFirst => (t("oldest"), blog::PostOrder::NewestFirst),
+            };
+            if ui
+                .small_button(order_label)
+                .on_hover_text(t("toggle_order_hover"))
+                .clicked()
+            {
+                self.post_order = toggled;
+                self.blog_posts.set_order(toggled);
+            }
+        });
+        let search_response =
+            ui.add(egui::TextEdit::singleline(&mut self.search_query).hint_text(t("search")));
+        if search_response.changed() {
+            self.post_list_page = 0;
+        }
+        self.jump_to_date_ui(ui);
+        let matches: Vec<usize> = self
+            .post_order
+            .apply(self.blog_posts.search(&self.search_query).into_iter())
+            .collect();
+        if let Some(index) = self.scroll_to_post
+            && let Some(position) = matches.iter().position(|&i| i == index)
+        {
+            self.post_list_page = position / self.posts_per_page;
+        }
+        self.pagination_ui(ui, matches.len());
+        let page_start = self.post_list_page * self.posts_per_page;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            let mut clicked = None;
+            let mut toggle_published = None;
+            let mut duplicate = None;
+            for &index in matches.iter().skip(page_start).take(self.posts_per_page) {
+                let post = &self.blog_posts.posts()[index];
+                let is_selected = self.selected_post == Some(index);
+                let row = ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!busy, egui::Button::new("🗑").small())
+                        .on_hover_text(t("delete_post_hover"))
+                        .clicked()
+                    {
+                        self.pending_delete = Some(index);
+                    }
+                    if ui
+                        .small_button("📋")
+                        .on_hover_text(t("copy_markdown"))
+                        .clicked()
+                    {
+                        ui.ctx().copy_text(post.to_markdown());
+                        self.result_text =
+                            i18n::tr_args(language, "copied_as_markdown", &[&post.title]);
+                    }
+                    if !post.published {
+                        ui.label(
+                            egui::RichText::new(t("draft_badge"))
+                                .small()
+                                .color(ui.visuals().warn_fg_color),
+                        );
+                    }
+                    let title = highlight_matches(ui, &post.title, &self.search_query);
+                    let title_response = ui.selectable_label(is_selected, title);
+                    if title_response.clicked() {
+                        clicked = Some(index);
+                    }
+                    title_response.context_menu(|ui| {
+                        let label = if post.published {
+                            t("mark_draft")
+                        } else {
+                            t("mark_published")
+                        };
+                        if ui.add_enabled(!busy, egui::Button::new(label)).clicked() {
+                            toggle_published = Some((index, !post.published));
+                        }
+                        if ui.button(t("duplicate")).clicked() {
+                            duplicate = Some(index);
+                        }
+                    });
+                });
+                if self.scroll_to_post == Some(index) {
+                    row.response.scroll_to_me(Some(egui::Align::Center));
+                    self.scroll_to_post = None;
+                }
+                let excerpt = post.excerpt(POST_EXCERPT_CHARS);
+                if !excerpt.is_empty() {
+                    ui.add(
+                        egui::Label::new(egui::RichText::new(excerpt).small().weak()).truncate(),
+                    );
+                }
+                if let Ok(time) = self.blog_posts.post_time(post) {
+                    relative_time_label(ui, time);
+                }
+            }
+            if let Some(index) = duplicate {
+                self.duplicate_post(index);
+            }
+            if let Some((index, published)) = toggle_published {
+                self.set_post_published(index, published);
+            }
+            if let Some(index) = clicked {
+                if self.selected_post == Some(index) {
+                    self.selected_post = None;
+                } else {
+                    self.edit_post(index);
+                }
+            }
+        });
+    }
+
+    /// Load the post at `index` into the editor for updating.
+    fn edit_post(&mut self, index: usize) {
+        let Some(post) = self.blog_posts.posts().get(index) else {
+            return;
+        };
+        self.title = post.title.clone();
+        self.content = post.content.join("\n");
+        self.tags = post.tags.join(", ");
+        self.published = post.published;
+        self.scheduled_time = None;
+        self.history.reset(&self.content);
+        self.selected_post = Some(index);
+    }
+
+    /// The earlier versions of the selected post, to look through and restore.
+    fn post_history_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let (Some(index), Some(posts_file)) = (self.selected_post, self.posts_file.clone()) else {
+            self.show_post_history = false;
+            return;
+        };
+        let Some(post) = self.blog_posts.posts().get(index) else {
+            return;
+        };
+        let has_unsaved_content = self.has_unsaved_content();
+        let busy = self.busy();
+        let mut restore = None;
+        egui::Window::new(i18n::tr_args(self.language, "history_of", &[&post.title]))
+            .open(&mut self.show_post_history)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                if post.history.is_empty() {
+                    ui.label(t("no_versions"));
+                    return;
+                }
+                ui.horizontal_top(|ui| {
+                    ui.vertical(|ui| {
+                        ui.set_width(180.0);
+                        for (version_index, version) in post.history.iter().enumerate().rev() {
+                            let selected = self.post_history_version == Some(version_index);
+                            let (label, replaced) = match self.blog_posts.post_time(version) {
+                                Ok(time) => {
+                                    (blog::relative_time(time, Local::now()), absolute_time(time))
+                                }
+                                Err(_) => (version.woa_time.clone(), version.woa_time.clone()),
+                            };
+                            if ui
+                                .selectable_label(selected, label)
+                                .on_hover_text(i18n::tr_args(
+                                    self.language,
+                                    "replaced_at",
+                                    &[&replaced, &version.excerpt(POST_EXCERPT_CHARS)],
+                                ))
+                                .clicked()
+                            {
+                                self.post_history_version = Some(version_index);
+                            }
+                        }
+                    });
+                    ui.separator();
+                    let Some(version) = self
+                        .post_history_version
+                        .and_then(|version_index| post.history.get(version_index))
+                    else {
+                        ui.weak(t("choose_version"));
+                        return;
+                    };
+                    ui.vertical(|ui| {
+                        ui.heading(&version.title);
+                        egui::ScrollArea::vertical()
+                            .max_height(EDITOR_HEIGHT)
+                            .show(ui, |ui| ui.label(version.content.join("\n")));
+                        if ui
+                            .add_enabled(
+                                !has_unsaved_content && !busy,
+                                egui::Button::new(t("restore")),
+                            )
+                            .on_hover_text(t("restore_version_hover"))
+                            .on_disabled_hover_text(t("update_or_discard_first"))
+                            .clicked()
+                        {
+                            restore = self.post_history_version;
+                        }
+                    });
+                });
+            });
+        if let Some(version) = restore {
+            match blog::restore_version(index, version, &mut self.blog_posts, &posts_file) {
+                Ok(_) => {
+                    self.result_text = t("version_restored").to_string();
+                    self.post_history_version = None;
+                    self.edit_post(index);
+                }
+                Err(e) => self.show_error(e),
+            }
+        }
+    }
+
+    /// Start a new post from a copy of the post at `index`. Nothing is saved until it's published.
+    fn duplicate_post(&mut self, index: usize) {
+        let Some(post) = self.blog_posts.posts().get(index) else {
+            return;
+        };
+        self.title = i18n::tr_args(self.language, "title_copy", &[&post.title]);
+        self.content = post.content.join("\n");
+        self.tags = post.tags.join(", ");
+        self.published = true;
+        self.scheduled_time = None;
+        self.selected_post = None;
+        self.history.reset(&self.content);
+    }
+
+    /// Pick a date to scroll the post list to the newest post from on or before it.
+    fn jump_to_date_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.horizontal(|ui| {
+            ui.label(t("go_to"));
+            let response = ui
+                .add(egui_extras::DatePickerButton::new(&mut self.jump_date).id_salt("jump_date"));
+            if response.changed() {
+                match self.blog_posts.index_for_date(self.jump_date) {
+                    Some(index) => {
+                        // So the post isn't filtered out of the list:
+                        self.search_query.clear();
+                        self.scroll_to_post = Some(index);
+                    }
+                    None => {
+                        self.result_text =
+                            i18n::tr_args(self.language, "no_posts_before", &[&self.jump_date])
+                    }
+                }
+            }
+        });
+    }
+
+    fn pagination_ui(&mut self, ui: &mut egui::Ui, post_count: usize) {
+        let t = |key| i18n::tr(self.language, key);
+        let page_count = post_count.div_ceil(self.posts_per_page).max(1);
+        self.post_list_page = self.post_list_page.min(page_count - 1);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.post_list_page > 0, egui::Button::new("◀").small())
+                .on_hover_text(t("previous_page"))
+                .clicked()
+            {
+                self.post_list_page -= 1;
+            }
+            ui.label(format!("{}/{}", self.post_list_page + 1, page_count));
+            if ui
+                .add_enabled(
+                    self.post_list_page + 1 < page_count,
+                    egui::Button::new("▶").small(),
+                )
+                .on_hover_text(t("next_page"))
+                .clicked()
+            {
+                self.post_list_page += 1;
+            }
+            ui.add(
+                egui::DragValue::new(&mut self.posts_per_page)
+                    .range(5..=200)
+                    .suffix(t("per_page")),
+            );
+        });
+    }
+
+    fn set_post_published(&mut self, index: usize, published: bool) {
+        if self.busy() {
+            return;
+        }
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        match blog::set_published(index, published, &mut self.blog_posts, &posts_file) {
+            Ok(_) => {
+                if self.selected_post == Some(index) {
+                    self.published = published;
+                }
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn status_bar_ui(&self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        let posts = self.blog_posts.posts();
+        let last_published = posts
+            .iter()
+            .filter_map(|post| self.blog_posts.post_time(post).ok())
+            .max();
+        let count_key = if posts.len() == 1 {
+            "post_count_one"
+        } else {
+            "post_count_many"
+        };
+        ui.horizontal(|ui| {
+            ui.weak(i18n::tr_args(self.language, count_key, &[&posts.len()]));
+            match last_published {
+                Some(time) => relative_time_label(ui, time),
+                None => ui.weak(t("never")),
+            };
+        });
+    }
+
+    /// Load the dictionary if spell checking is on and it isn't loaded for the current
+    /// language and posts file, turning spell checking off if that fails.
+    fn ensure_spell_checker(&mut self) {
+        if !self.spell_check {
+            self.spell_checker = None;
+            return;
+        }
+        let posts_file = self.posts_file.as_deref();
+        if self
+            .spell_checker
+            .as_ref()
+            .is_some_and(|checker| checker.is_for(&self.spell_check_language, posts_file))
+        {
+            return;
+        }
+        match SpellChecker::load(&self.spell_check_language, posts_file) {
+            Ok(checker) => self.spell_checker = Some(checker),
+            Err(e) => {
+                self.error_text = Some(e);
+                self.spell_check = false;
+                self.spell_checker = None;
+            }
+        }
+    }
+
+    fn spell_check_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.checkbox(&mut self.spell_check, t("spelling"));
+        if !self.spell_check {
+            return;
+        }
+        egui::ComboBox::from_id_salt("spell_check_language")
+            .selected_text(&self.spell_check_language)
+            .show_ui(ui, |ui| {
+                for language in spelling::available_languages() {
+                    ui.selectable_value(&mut self.spell_check_language, language.clone(), language);
+                }
+            })
+            .response
+            .on_hover_text(t("spelling_dictionary"));
+    }
+
+    fn overuse_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.checkbox(&mut self.highlight_overused, t("overused_words"))
+            .on_hover_text(t("overused_hover"));
+        if !self.highlight_overused {
+            return;
+        }
+        ui.menu_button("⚙", |ui| {
+            ui.horizontal(|ui| {
+                ui.label(t("more_than"));
+                ui.add(egui::DragValue::new(&mut self.overuse_threshold).range(1..=100));
+                ui.label(t("times"));
+            });
+            ui.label(t("never_highlight"));
+            ui.add(
+                egui::TextEdit::multiline(&mut self.stopwords)
+                    .desired_rows(4)
+                    .desired_width(250.0),
+            );
+            if ui.button(t("reset_to_default")).clicked() {
+                self.overuse_threshold = overuse::DEFAULT_THRESHOLD;
+                self.stopwords = overuse::DEFAULT_STOPWORDS.to_owned();
+            }
+        })
+        .response
+        .on_hover_text(t("overused_settings"));
+    }
+
+    /// The context menu of a misspelled word: its suggestions and adding it to the personal
+    /// word list.
+    fn spelling_menu_ui(&mut self, response: &egui::Response) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some((range, suggestions)) = self.spelling_target.clone() else {
+            return;
+        };
+        let Some(word) = self.content.get(range.clone()).map(str::to_owned) else {
+            self.spelling_target = None;
+            return;
+        };
+        let mut replacement = None;
+        let mut add_word = false;
+        let menu = response.context_menu(|ui| {
+            if suggestions.is_empty() {
+                ui.weak(t("no_suggestions"));
+            }
+            for suggestion in suggestions.iter().take(8) {
+                if ui.button(suggestion).clicked() {
+                    replacement = Some(suggestion.clone());
+                }
+            }
+            ui.separator();
+            if ui
+                .button(i18n::tr_args(self.language, "add_to_dictionary", &[&word]))
+                .clicked()
+            {
+                add_word = true;
+            }
+        });
+        if menu.is_none() {
+            self.spelling_target = None;
+        }
+        if let Some(replacement) = replacement {
+            self.content.replace_range(range, &replacement);
+            self.spelling_target = None;
+        }
+        if add_word {
+            if let Some(checker) = &mut self.spell_checker
+                && let Err(e) = checker.add_word(&word)
+            {
+                self.error_text = Some(i18n::tr_args(
+                    self.language,
+                    "could_not_add_word",
+                    &[&word, &e],
+                ));
+            }
+            self.spelling_target = None;
+        }
+    }
+
+    fn content_editor_ui(&mut self, ui: &mut egui::Ui, height: f32) {
+        self.ensure_spell_checker();
+        let wrap = self.soft_wrap;
+        let font_id = egui::FontId::new(self.editor_font_size, self.editor_font.family());
+        let gutter_width = if self.show_line_numbers {
+            let digits = self.content.split('\n').count().to_string().len();
+            let digit_width = ui.fonts_mut(|fonts| fonts.glyph_width(&font_id, '0'));
+            digits as f32 * digit_width + 8.0
+        } else {
+            0.0
+        };
+        let content = &mut self.content;
+        let spell_checker = &mut self.spell_checker;
+        let tab_indents = self.tab_indents;
+        let mut overuse = self.highlight_overused.then_some((
+            &mut self.overuse_checker,
+            self.overuse_threshold,
+            self.stopwords.as_str(),
+        ));
+        let mut spelling_target = None;
+        let content_response = with_error_border(ui, self.content_invalid, |ui| {
+            // The gutter is inside the scroll area so it scrolls along with the text:
+            egui::ScrollArea::new([!wrap, true])
+                .id_salt("content_scroll")
+                .max_height(height)
+                .show(ui, |ui| {
+                    ui.horizontal_top(|ui| {
+                        ui.add_space(gutter_width);
+                        let mut layouter =
+                            |ui: &egui::Ui, text: &dyn egui::TextBuffer, wrap_width: f32| {
+                                let misspelled = spell_checker
+                                    .as_mut()
+                                    .map(|checker| checker.misspelled(text.as_str()))
+                                    .unwrap_or_default();
+                                let overused = overuse
+                                    .as_mut()
+                                    .map(|(checker, threshold, stopwords)| {
+                                        checker.overused(text.as_str(), *threshold, stopwords)
+                                    })
+                                    .unwrap_or_default();
+                                let mut job = marked_layout_job(
+                                    ui,
+                                    text.as_str(),
+                                    misspelled,
+                                    overused,
+                                    font_id.clone(),
+                                );
+                                job.wrap.max_width = if wrap { wrap_width } else { f32::INFINITY };
+                                ui.fonts_mut(|fonts| fonts.layout_job(job))
+                            };
+                        let output = egui::TextEdit::multiline(content)
+                            .id(egui::Id::new(CONTENT_EDITOR_ID))
+                            // Keeps Tab from moving focus away:
+                            .lock_focus(tab_indents)
+                            .desired_width(f32::INFINITY)
+                            .desired_rows(10)
+                            .layouter(&mut layouter)
+                            .show(ui);
+                        if gutter_width > 0.0 {
+                            line_numbers_ui(ui, &output, &font_id);
+                        }
+                        if output.response.secondary_clicked()
+                            && let Some(checker) = spell_checker.as_mut()
+                            && let Some(pointer) = output.response.interact_pointer_pos()
+                        {
+                            let cursor = output.galley.cursor_from_pos(pointer - output.galley_pos);
+                            let index = editor::byte_index(content, cursor.index);
+                            spelling_target = spelling::word_at(content, index)
+                                .filter(|word| checker.misspelled(content).contains(word))
+                                .map(|word| {
+                                    let suggestions = checker.suggestions(&content[word.clone()]);
+                                    (word, suggestions)
+                                });
+                        }
+                        output.response
+                    })
+                    .inner
+                })
+                .inner
+        });
+        if content_response.changed() {
+            self.content_invalid = false;
+        }
+        if spelling_target.is_some() {
+            self.spelling_target = spelling_target;
+        }
+        self.spelling_menu_ui(&content_response);
+        let now = ui.input(|i| i.time);
+        if self
+            .history
+            .track(&self.content, now, content_response.changed())
+        {
+            ui.ctx()
+                .request_repaint_after_secs(history::DEBOUNCE_SECS as f32);
+        }
+        let (words, characters, minutes) = blog::content_stats(&self.content);
+        ui.horizontal(|ui| {
+            ui.weak(i18n::tr_args(
+                self.language,
+                "content_stats",
+                &[&words, &characters, &minutes],
+            ));
+            if let Some(max) = self.blog_posts.page().max_words {
+                let max = max as usize;
+                if words > max {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        i18n::tr_args(self.language, "words_over_limit", &[&(words - max), &max]),
+                    );
+                } else {
+                    ui.weak(i18n::tr_args(
+                        self.language,
+                        "words_left",
+                        &[&(max - words)],
+                    ));
+                }
+            }
+        });
+    }
+
+    /// Indent with Tab and dedent with Shift+Tab in the content editor, if `tab_indents` is
+    /// on. The keys are taken before the `TextEdit` would insert its own tab.
+    fn handle_indent_keys(&mut self, ctx: &egui::Context) {
+        let id = egui::Id::new(CONTENT_EDITOR_ID);
+        if !self.tab_indents || !ctx.memory(|m| m.has_focus(id)) {
+            return;
+        }
+        let dedent = ctx.input_mut(|i| i.consume_key(egui::Modifiers::SHIFT, egui::Key::Tab));
+        let indent = ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Tab));
+        if dedent {
+            editor::dedent_line(ctx, id, &mut self.content, self.indent);
+        } else if indent {
+            editor::insert_at_cursor(ctx, id, &mut self.content, &self.indent.text());
+        }
+    }
+
+    fn indent_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.checkbox(&mut self.tab_indents, t("tab_indents"))
+            .on_hover_text(t("tab_indents_hover"));
+        if !self.tab_indents {
+            return;
+        }
+        egui::ComboBox::from_id_salt("indent")
+            .selected_text(self.indent.label(self.language))
+            .show_ui(ui, |ui| {
+                for indent in editor::Indent::CHOICES {
+                    ui.selectable_value(&mut self.indent, indent, indent.label(self.language));
+                }
+            });
+    }
+
+    fn handle_history_shortcuts(&mut self, ctx: &egui::Context) {
+        // Only when the content editor is focused, so the title keeps its own undo:
+        if !ctx.memory(|m| m.has_focus(egui::Id::new(CONTENT_EDITOR_ID))) {
+            return;
+        }
+        // Consume the keys before the `TextEdit` sees them and applies its own undo:
+        let redo = ctx.input_mut(|i| {
+            i.consume_shortcut(&REDO_SHIFT_SHORTCUT) || i.consume_shortcut(&REDO_SHORTCUT)
+        });
+        let undo = ctx.input_mut(|i| i.consume_shortcut(&UNDO_SHORTCUT));
+        if redo {
+            self.history.redo(&mut self.content);
+        } else if undo {
+            self.history.undo(&mut self.content);
+        }
+    }
+
+    /// The content's changes since the post being edited was last saved. Only shown while
+    /// editing a post, and only worked out while it's expanded.
+    fn changes_ui(&self, ui: &mut egui::Ui) {
+        let Some(post) = self
+            .selected_post
+            .and_then(|index| self.blog_posts.posts().get(index))
+        else {
+            return;
+        };
+        let t = |key| i18n::tr(self.language, key);
+        egui::CollapsingHeader::new(t("changes"))
+            .id_salt("content_changes")
+            .show(ui, |ui| {
+                let changes = blog::diff_content(&post.content, &self.content);
+                if changes
+                    .iter()
+                    .all(|change| matches!(change, blog::LineChange::Unchanged(_)))
+                {
+                    ui.weak(t("no_changes"));
+                    return;
+                }
+                let added = if ui.visuals().dark_mode {
+                    egui::Color32::LIGHT_GREEN
+                } else {
+                    egui::Color32::DARK_GREEN
+                };
+                let removed = ui.visuals().error_fg_color;
+                egui::ScrollArea::vertical()
+                    .id_salt("content_changes_scroll")
+                    .max_height(CHANGES_HEIGHT)
+                    .show(ui, |ui| {
+                        for change in changes {
+                            let text = match change {
+                                blog::LineChange::Unchanged(line) => {
+                                    egui::RichText::new(format!("  {}", line)).weak()
+                                }
+                                blog::LineChange::Added(line) => {
+                                    egui::RichText::new(format!("+ {}", line)).color(added)
+                                }
+                                blog::LineChange::Removed(line) => {
+                                    egui::RichText::new(format!("- {}", line)).color(removed)
+                                }
+                            };
+                            ui.label(text.monospace());
+                        }
+                    });
+            });
+    }
+
+    fn content_toolbar_ui(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        use egui::RichText;
+
+        let id = egui::Id::new(CONTENT_EDITOR_ID);
+        ui.horizontal(|ui| {
+            if ui
+                .button(RichText::new("B").strong())
+                .on_hover_text(t("bold"))
+                .clicked()
+            {
+                editor::wrap_selection(ui.ctx(), id, &mut self.content, "**");
+                ui.memory_mut(|m| m.request_focus(id));
+            }
+            if ui
+                .button(RichText::new("I").italics())
+                .on_hover_text(t("italic"))
+                .clicked()
+            {
+                editor::wrap_selection(ui.ctx(), id, &mut self.content, "*");
+                ui.memory_mut(|m| m.request_focus(id));
+            }
+            if ui.button("H2").on_hover_text(t("heading")).clicked() {
+                editor::prefix_line(ui.ctx(), id, &mut self.content, "## ");
+                ui.memory_mut(|m| m.request_focus(id));
+            }
+            ui.separator();
+            if ui
+                .add_enabled(self.posts_file.is_some(), egui::Button::new("🖼"))
+                .on_hover_text(t("insert_image"))
+                .on_disabled_hover_text(t("images_need_posts_file"))
+                .clicked()
+            {
+                self.insert_image(ui.ctx());
+            }
+            if ui.button("🕒").on_hover_text(t("insert_date")).clicked() {
+                self.insert_into_content(
+                    ui.ctx(),
+                    &Local::now().format(INSERTED_TIME_FORMAT).to_string(),
+                );
+            }
+            // Stays open while typing in the search box:
+            let picker = egui::containers::menu::MenuConfig::new()
+                .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside);
+            egui::containers::menu::MenuButton::new("😀")
+                .config(picker)
+                .ui(ui, |ui| self.emoji_picker_ui(ui))
+                .0
+                .on_hover_text(t("insert_emoji"));
+        });
+    }
+
+    fn emoji_picker_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.add(
+            egui::TextEdit::singleline(&mut self.emoji_search)
+                .hint_text(t("search"))
+                .desired_width(200.0),
+        );
+        let mut chosen = None;
+        egui::ScrollArea::vertical()
+            .max_height(250.0)
+            .show(ui, |ui| {
+                let mut any = false;
+                for category in emoji::CATEGORIES {
+                    let matches = emoji::matching(category, &self.emoji_search);
+                    if matches.is_empty() {
+                        continue;
+                    }
+                    any = true;
+                    ui.weak(category.name);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.set_max_width(200.0);
+                        for emoji in matches {
+                            if ui.button(egui::RichText::new(emoji).size(18.0)).clicked() {
+                                chosen = Some(emoji);
+                            }
+                        }
+                    });
+                }
+                if !any {
+                    ui.weak(t("no_matching_emoji"));
+                }
+            });
+        if let Some(emoji) = chosen {
+            self.insert_into_content(ui.ctx(), emoji);
+            self.emoji_search.clear();
+            ui.close();
+        }
+    }
+
+    fn scheduled_time_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        use chrono::Timelike;
+
+        let mut scheduled = self.scheduled_time.is_some();
+        let label = match self.selected_post {
+            Some(_) => t("change_date"),
+            None => t("set_date"),
+        };
+        if ui
+            .checkbox(&mut scheduled, label)
+            .on_hover_text(t("set_date_hover"))
+            .changed()
+        {
+            self.scheduled_time = scheduled.then(Local::now);
+        }
+        if let Some(time) = self.scheduled_time {
+            let mut date = time.date_naive();
+            let mut hour = time.hour();
+            let mut minute = time.minute();
+            ui.add(egui_extras::DatePickerButton::new(&mut date).id_salt("scheduled_date"));
+            ui.add(egui::DragValue::new(&mut hour).range(0..=23));
+            ui.label(":");
+            ui.add(egui::DragValue::new(&mut minute).range(0..=59));
+            if let Some(time) = date
+                .and_hms_opt(hour, minute, 0)
+                .and_then(|naive| naive.and_local_timezone(Local).earliest())
+            {
+                self.scheduled_time = Some(time);
+            }
+        }
+    }
+
+    /// Load the font file at `path` for the editor, going back to the proportional font if
+    /// it can't be read.
+    fn set_custom_font(&mut self, ctx: &egui::Context, path: PathBuf) {
+        match fonts::install_custom_font(ctx, &path) {
+            Ok(()) => self.custom_font = Some(path),
+            Err(e) => {
+                self.error_text = Some(i18n::tr_args(
+                    self.language,
+                    "could_not_load_font",
+                    &[&path.display(), &e],
+                ));
+                self.custom_font = None;
+                if self.editor_font == EditorFont::Custom {
+                    self.editor_font = EditorFont::Proportional;
+                }
+            }
+        }
+    }
+
+    fn editor_font_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        let mut choice = self.editor_font;
+        egui::ComboBox::from_id_salt("editor_font")
+            .selected_text(t(self.editor_font.label_key()))
+            .show_ui(ui, |ui| {
+                for font in [
+                    EditorFont::Proportional,
+                    EditorFont::Monospace,
+                    EditorFont::Custom,
+                ] {
+                    ui.selectable_value(&mut choice, font, t(font.label_key()));
+                }
+            })
+            .response
+            .on_hover_text(t("editor_font"));
+        if choice == self.editor_font {
+            return;
+        }
+        if choice == EditorFont::Custom {
+            let Some(path) = rfd::FileDialog::new()
+                .add_filter(t("fonts"), &["ttf", "otf"])
+                .pick_file()
+            else {
+                return;
+            };
+            self.set_custom_font(ui.ctx(), path);
+            if self.custom_font.is_none() {
+                return;
+            }
+        }
+        self.editor_font = choice;
+    }
+
+    fn editor_font_size_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.menu_button(format!("{} pt", self.editor_font_size), |ui| {
+            ui.add(
+                egui::Slider::new(&mut self.editor_font_size, EDITOR_FONT_SIZES)
+                    .step_by(1.0)
+                    .suffix(" pt"),
+            );
+            ui.weak(t("zoom_shortcut"));
+            if ui.button(t("reset_to_default")).clicked() {
+                self.editor_font_size = DEFAULT_EDITOR_FONT_SIZE;
+            }
+        })
+        .response
+        .on_hover_text(t("editor_font_size"));
+    }
+
+    fn handle_font_size_shortcuts(&mut self, ctx: &egui::Context) {
+        let bigger = ctx.input_mut(|i| {
+            i.consume_shortcut(&FONT_BIGGER_SHORTCUT)
+                || i.consume_shortcut(&FONT_BIGGER_SECONDARY_SHORTCUT)
+        });
+        let smaller = ctx.input_mut(|i| i.consume_shortcut(&FONT_SMALLER_SHORTCUT));
+        let step = match (bigger, smaller) {
+            (true, false) => 1.0,
+            (false, true) => -1.0,
+            _ => return,
+        };
+        self.editor_font_size = (self.editor_font_size + step)
+            .clamp(*EDITOR_FONT_SIZES.start(), *EDITOR_FONT_SIZES.end());
+    }
+
+    fn handle_zen_mode_shortcut(&mut self, ctx: &egui::Context) {
+        let toggle = ctx.input_mut(|i| i.consume_shortcut(&ZEN_MODE_SHORTCUT));
+        let leave = self.zen_mode
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape));
+        if toggle || leave {
+            self.zen_mode = !self.zen_mode;
+        }
+    }
+
+    /// Only the content editor, centered, for distraction-free writing.
+    fn zen_ui(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        ui.vertical_centered(|ui| {
+            ui.set_max_width(ZEN_MAX_WIDTH);
+            ui.weak(t("leave_zen"));
+            let height = ui.available_height() - 30.0;
+            self.content_editor_ui(ui, height);
+        });
+    }
+
+    /// Focus the content editor on Ctrl+L, and when the window is switched to with nothing
+    /// else focused, so typing can start straight away.
+    fn handle_focus_content(&self, ctx: &egui::Context) {
+        let shortcut = ctx.input_mut(|i| i.consume_shortcut(&FOCUS_CONTENT_SHORTCUT));
+        let window_focused = ctx.input(|i| {
+            i.events
+                .iter()
+                .any(|event| matches!(event, egui::Event::WindowFocused(true)))
+        });
+        let nothing_focused = ctx.memory(|m| m.focused().is_none());
+        if shortcut || (window_focused && nothing_focused) {
+            ctx.memory_mut(|m| m.request_focus(egui::Id::new(CONTENT_EDITOR_ID)));
+        }
+    }
+
+    fn handle_find_replace_shortcut(&mut self, ctx: &egui::Context) {
+        if ctx.input_mut(|i| i.consume_shortcut(&FIND_REPLACE_SHORTCUT)) {
+            self.show_find_replace = !self.show_find_replace;
+        }
+    }
+
+    fn find_replace_ui(&mut self, ui: &mut egui::Ui) {
+        let t = |key| i18n::tr(self.language, key);
+        if !self.show_find_replace {
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label(t("find"));
+            ui.text_edit_singleline(&mut self.find_text);
+            ui.label(t("replace_with"));
+            ui.text_edit_singleline(&mut self.replace_text);
+            ui.checkbox(&mut self.find_case_sensitive, t("match_case"));
+            if ui
+                .add_enabled(
+                    !self.find_text.is_empty(),
+                    egui::Button::new(t("replace_all")),
+                )
+                .clicked()
+            {
+                self.content = blog::replace_all(
+                    &self.content,
+                    &self.find_text,
+                    &self.replace_text,
+                    self.find_case_sensitive,
+                );
+            }
+            if ui.button("✖").on_hover_text(t("close")).clicked() {
+                self.show_find_replace = false;
+            }
+        });
+    }
+
+    fn insert_image(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        let Some(image) = rfd::FileDialog::new()
+            .add_filter(t("images"), &["png", "jpg", "jpeg", "gif", "webp", "svg"])
+            .pick_file()
+        else {
+            return;
+        };
+        match blog::copy_image(&image, &posts_file) {
+            Ok(reference) => self.insert_into_content(ctx, &format!("![]({})", reference)),
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    /// Paste an image from the clipboard into the images folder and reference it. egui only
+    /// pastes text, and sends nothing for Ctrl+V when there's none, so this goes by the V key
+    /// being let go of and reads the clipboard itself.
+    fn handle_image_paste(&mut self, ctx: &egui::Context) {
+        let paste_released = ctx.input(|i| {
+            i.events.iter().any(|event| {
+                matches!(
+                    event,
+                    egui::Event::Key { key: egui::Key::V, pressed: false, modifiers, .. }
+                        if modifiers.command
+                )
+            })
+        });
+        if !paste_released || !ctx.memory(|m| m.has_focus(egui::Id::new(CONTENT_EDITOR_ID))) {
+            return;
+        }
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        let Ok(mut clipboard) = arboard::Clipboard::new() else {
+            return;
+        };
+        // Text was pasted as usual:
+        if clipboard.get_text().is_ok_and(|text| !text.is_empty()) {
+            return;
+        }
+        let Ok(image) = clipboard.get_image() else {
+            return;
+        };
+        let (width, height) = (image.width as u32, image.height as u32);
+        match blog::save_image(&image.bytes, width, height, &posts_file) {
+            Ok(reference) => self.insert_into_content(ctx, &format!("![]({})", reference)),
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn insert_into_content(&mut self, ctx: &egui::Context, text: &str) {
+        let id = egui::Id::new(CONTENT_EDITOR_ID);
+        editor::insert_at_cursor(ctx, id, &mut self.content, text);
+        ctx.memory_mut(|m| m.request_focus(id));
+    }
+
+    /// Whether a publish is being saved or an import or export is running in the background.
+    /// Each works on a copy of the posts, which would overwrite anything else changed in them
+    /// meanwhile, so nothing else that changes them can run until it's done.
+    fn busy(&self) -> bool {
+        self.pending_save.is_some() || self.bulk_operation.is_some()
+    }
+
+    /// Whether the editor holds something that hasn't been published (or updated) yet.
+    fn has_unsaved_content(&self) -> bool {
+        // A chosen date is only kept in the editor until it's published:
+        if self.scheduled_time.is_some() {
+            return true;
+        }
+        match self
+            .selected_post
+            .and_then(|index| self.blog_posts.posts().get(index))
+        {
+            Some(post) => post.is_edited(&self.title, &self.content, &self.tags, self.published),
+            None => !blog::is_unedited(
+                &self.title,
+                &self.content,
+                &self.tags,
+                (&self.default_title(), DEFAULT_CONTENT),
+            ),
+        }
+    }
+
+    /// Put the tray icon up or take it down to match `minimize_to_tray`.
+    fn sync_tray(&mut self, ctx: &egui::Context) {
+        if !self.minimize_to_tray {
+            self.tray = None;
+        } else if self.tray.is_none() {
+            match tray::Tray::new(ctx, self.language) {
+                Ok(tray) => self.tray = Some(tray),
+                Err(e) => {
+                    self.minimize_to_tray = false;
+                    self.error_text =
+                        Some(i18n::tr_args(self.language, "could_not_add_tray", &[&e]));
+                }
+            }
+        }
+    }
+
+    fn handle_close_request(&mut self, ctx: &egui::Context) {
+        if ctx.input(|i| i.viewport().close_requested())
+            && !self.close_confirmed
+            && self.has_unsaved_content()
+        {
+            ctx.send_viewport_cmd(ViewportCommand::CancelClose);
+            self.show_close_confirmation = true;
+        }
+    }
+
+    /// The title a new post starts with: today's date if `date_titles` is on, falling back to
+    /// `DEFAULT_TITLE` while the format isn't valid.
+    fn default_title(&self) -> String {
+        use std::fmt::Write;
+
+        if !self.date_titles {
+            return DEFAULT_TITLE.to_string();
+        }
+        let mut title = String::new();
+        match write!(title, "{}", Local::now().format(&self.date_title_format)) {
+            Ok(()) => title,
+            Err(_) => DEFAULT_TITLE.to_string(),
+        }
+    }
+
+    /// Reset the editor to a fresh post, leaving any post being edited unchanged.
+    fn new_post(&mut self) {
+        self.title = self.default_title();
+        self.content = "".to_string();
+        self.tags = "".to_string();
+        self.published = true;
+        self.scheduled_time = None;
+        self.selected_post = None;
+        self.result_text = "".to_string();
+        self.error_text = None;
+        self.title_invalid = false;
+        self.content_invalid = false;
+        self.history.reset(&self.content);
+    }
+
+    fn duplicate_title_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        if !self.show_duplicate_title_confirmation {
+            return;
+        }
+        let mut publish = false;
+        let mut cancelled = false;
+        let modal =
+            egui::Modal::new(egui::Id::new("duplicate_title_confirmation")).show(ctx, |ui| {
+                ui.label(i18n::tr_args(
+                    self.language,
+                    "duplicate_title",
+                    &[&self.title.trim()],
+                ));
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button(t("publish")).clicked() {
+                        publish = true;
+                    }
+                    if ui.button(t("cancel")).clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+        if publish && let Some(posts_file) = self.posts_file.clone() {
+            self.review_publish(&posts_file);
+        }
+        if publish || cancelled || modal.should_close() {
+            self.show_duplicate_title_confirmation = false;
+        }
+    }
 
-mod blog;
+    fn publish_plan_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let (Some(plan), Some(posts_file)) = (&self.pending_publish_plan, self.posts_file.clone())
+        else {
+            return;
+        };
+        let mut publish = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("publish_plan_confirmation")).show(ctx, |ui| {
+            ui.heading(t("publish_this_post"));
+            if plan.creates_file {
+                ui.label(i18n::tr_args(
+                    self.language,
+                    "will_be_created",
+                    &[&posts_file.display()],
+                ));
+            }
+            ui.label(i18n::tr_args(
+                self.language,
+                "publish_plan_summary",
+                &[&self.title.trim(), &plan.woa_time, &plan.slug],
+            ));
+            ui.label(i18n::tr_args(
+                self.language,
+                "blog_will_have",
+                &[&plan.post_count],
+            ));
+            ui.add_space(10.0);
+            ui.checkbox(&mut self.confirm_publish, t("always_show_plan"));
+            ui.horizontal(|ui| {
+                if ui.button(t("publish")).clicked() {
+                    publish = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if publish {
+            self.start_publish(&posts_file);
+        }
+        if publish || cancelled || modal.should_close() {
+            self.pending_publish_plan = None;
+        }
+    }
 
-fn main() -> eframe::Result {
-    env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
-    let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_icon(IconData {
-                rgba: image::load_from_memory(include_bytes!("../assets/woa_logo_only.png"))
-                    .unwrap()
-                    .to_rgba8()
-                    .to_vec(),
-                width: 400,
-                height: 270,
+    fn clear_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        if !self.show_clear_confirmation {
+            return;
+        }
+        let mut clear = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("clear_confirmation")).show(ctx, |ui| {
+            ui.label(t("discard_prompt"));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button(t("discard")).clicked() {
+                    clear = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if clear {
+            self.new_post();
+        }
+        if clear || cancelled || modal.should_close() {
+            self.show_clear_confirmation = false;
+        }
+    }
+
+    fn close_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        if !self.show_close_confirmation {
+            return;
+        }
+        let mut close = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("close_confirmation")).show(ctx, |ui| {
+            ui.label(t("close_prompt"));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button(t("close")).clicked() {
+                    close = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if close {
+            self.close_confirmed = true;
+            ctx.send_viewport_cmd(ViewportCommand::Close);
+        }
+        if close || cancelled || modal.should_close() {
+            self.show_close_confirmation = false;
+        }
+    }
+
+    fn current_post(&self) -> blog::Post {
+        let mut content = blog::split_on_line_breaks(&self.content);
+        if self.autolink_on_publish {
+            content = content.iter().map(|line| blog::autolink(line)).collect();
+        }
+        blog::Post {
+            title: self.title.to_string(),
+            content,
+            tags: parse_tags(&self.tags),
+            published: self.published,
+            scheduled_time: self.scheduled_time,
+        }
+    }
+
+    fn request_publish(&mut self) {
+        if self.busy() {
+            return;
+        }
+        // A server gets the post as it is, without a posts file or anything to review:
+        if matches!(self.publish_target, blog::PublishTarget::Http(_)) {
+            if self.selected_post.is_some() {
+                self.error_text = Some(i18n::tr(self.language, "no_http_update").to_string());
+            } else {
+                self.publish_current(None);
+            }
+            return;
+        }
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        if self.selected_post.is_none() && self.blog_posts.title_exists(&self.title) {
+            self.show_duplicate_title_confirmation = true;
+            return;
+        }
+        self.review_publish(&posts_file);
+    }
+
+    /// Show what publishing a new post will do first, if that's switched on.
+    fn review_publish(&mut self, posts_file: &Path) {
+        if self.selected_post.is_some() || !self.confirm_publish {
+            self.start_publish(posts_file);
+            return;
+        }
+        match blog::publish_dry_run(&self.current_post(), &self.blog_posts, posts_file) {
+            Ok(plan) => self.pending_publish_plan = Some(plan),
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    fn start_publish(&mut self, posts_file: &Path) {
+        // Ask for a page title before creating a new posts file,
+        // unless publishing is going to fail anyway:
+        if posts_file.exists()
+            || self
+                .current_post()
+                .validate(self.blog_posts.page())
+                .is_err()
+        {
+            self.publish_current(Some(posts_file));
+        } else {
+            self.new_site_title = Some("".to_string());
+        }
+    }
+
+    fn handle_publish_shortcut(&mut self, ctx: &egui::Context) {
+        if !ctx.input(|i| i.focused) {
+            return;
+        }
+        // Consumed before the content `TextEdit` runs, so it doesn't also insert a newline,
+        // even when it can't publish yet:
+        if ctx.input_mut(|i| i.consume_shortcut(&PUBLISH_SHORTCUT)) && !self.busy() {
+            self.request_publish();
+        }
+    }
+
+    fn handle_save_shortcut(&mut self, ctx: &egui::Context) {
+        if ctx.input_mut(|i| i.consume_shortcut(&SAVE_SHORTCUT)) && !self.busy() {
+            self.save_settings();
+        }
+    }
+
+    /// Write the page settings and footer links as they are in the settings window, leaving
+    /// the post in the editor alone.
+    fn save_settings(&mut self) {
+        let t = |key| i18n::tr(self.language, key);
+        if self.busy() {
+            return;
+        }
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        match blog::save_settings(
+            self.page.clone(),
+            self.footer_links.clone(),
+            &mut self.blog_posts,
+            &posts_file,
+        ) {
+            Ok(_) => self.result_text = t("saved").to_string(),
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    /// Save the post in the editor on a background thread, so a slow disk doesn't freeze the
+    /// window, or send it to the HTTP endpoint, which needs no posts file. `finish_publish`
+    /// picks up the result.
+    fn publish_current(&mut self, posts_file: Option<&Path>) {
+        let t = |key| i18n::tr(self.language, key);
+        if self.busy() {
+            return;
+        }
+        let post = self.current_post();
+        let size_kb = post.json_size().div_ceil(1024);
+        let selected_post = self.selected_post;
+        let mut blog_posts = self.blog_posts.clone();
+        let path = posts_file.map(Path::to_path_buf);
+        let target = self.publish_target.clone();
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let result = match (target, selected_post, path) {
+                (blog::PublishTarget::Http(url), _, _) => {
+                    blog::publish_to_url(post, blog_posts.page(), &url).map(|_| true)
+                }
+                (blog::PublishTarget::File, Some(index), Some(path)) => {
+                    blog::update(index, post, &mut blog_posts, &path)
+                }
+                (blog::PublishTarget::File, None, Some(path)) => {
+                    blog::publish(post, &mut blog_posts, &path)
+                }
+                (blog::PublishTarget::File, _, None) => Err(blog::BlogError::FileNotFound),
+            };
+            let _ = sender.send((result, blog_posts));
+        });
+        self.pending_save = Some(PendingSave {
+            receiver,
+            action: match selected_post {
+                Some(_) => "update",
+                None => "publish",
+            },
+            message: match selected_post {
+                Some(_) => t("update_successful"),
+                None => t("publish_successful"),
+            },
+            size_kb,
+            posts_file: posts_file.map(Path::to_path_buf),
+            submitted: self.current_draft(),
+        });
+    }
+
+    fn finish_publish(&mut self, ctx: &egui::Context) {
+        let Some(pending) = self.pending_save.take() else {
+            return;
+        };
+        let (result, blog_posts) = match pending.receiver.try_recv() {
+            Ok(outcome) => outcome,
+            Err(mpsc::TryRecvError::Empty) => {
+                self.pending_save = Some(pending);
+                ctx.request_repaint_after_secs(0.1);
+                return;
+            }
+            // The save thread panicked:
+            Err(mpsc::TryRecvError::Disconnected) => (
+                Err(blog::BlogError::CouldNotWritePostsFile),
+                self.blog_posts.clone(),
+            ),
+        };
+        self.title_invalid = matches!(result, Err(blog::BlogError::EmptyTitle));
+        self.content_invalid = matches!(result, Err(blog::BlogError::EmptyContent));
+        if let Some(posts_file) = &pending.posts_file {
+            self.log_action(
+                posts_file,
+                pending.action,
+                &pending.submitted.title,
+                result.as_ref().err(),
+            );
+        }
+        match result {
+            Ok(_) => {
+                let before = std::mem::replace(&mut self.blog_posts, blog_posts);
+                // Nothing changed locally if the post went to a server:
+                if self.publish_target == blog::PublishTarget::File {
+                    self.record_undo(pending.action, before);
+                }
+                self.result_text = pending.message.to_string();
+                // Only a heads-up, the post is published either way:
+                if pending.size_kb > self.post_size_warning_kb {
+                    self.result_text = i18n::tr_args(
+                        self.language,
+                        "size_warning",
+                        &[
+                            &pending.message,
+                            &pending.size_kb,
+                            &self.post_size_warning_kb,
+                        ],
+                    );
+                }
+                if self.current_draft() == pending.submitted {
+                    self.title = self.default_title();
+                    self.content = "".to_string();
+                    self.tags = "".to_string();
+                    self.published = true;
+                    self.scheduled_time = None;
+                    self.history.reset(&self.content);
+                }
+                self.selected_post = None;
+                if let Some(posts_file) = &pending.posts_file {
+                    Draft::clear(posts_file);
+                }
+                self.autosaved_draft = self.current_draft();
+            }
+            Err(e) => self.show_error(e),
+        }
+    }
+
+    /// The editor and the preview side by side, with a divider between them to drag.
+    /// Double-clicking the divider shares the width evenly again.
+    fn editor_and_preview_ui(&mut self, ui: &mut egui::Ui) {
+        use egui::{CursorIcon, Layout, Sense, vec2};
+
+        let spacing = ui.spacing().item_spacing.x;
+        let panes_width = ui.available_width() - SPLITTER_WIDTH - 2.0 * spacing;
+        let min_split = (MIN_PANE_WIDTH / panes_width).min(0.5);
+        self.preview_split = self.preview_split.clamp(min_split, 1.0 - min_split);
+        let editor_width = panes_width * self.preview_split;
+        ui.horizontal_top(|ui| {
+            let layout = Layout::top_down(egui::Align::Min);
+            ui.allocate_ui_with_layout(vec2(editor_width, EDITOR_HEIGHT), layout, |ui| {
+                ui.set_width(editor_width);
+                self.content_editor_ui(ui, EDITOR_HEIGHT);
+            });
+            let (rect, response) = ui
+                .allocate_exact_size(vec2(SPLITTER_WIDTH, EDITOR_HEIGHT), Sense::click_and_drag());
+            let response = response.on_hover_cursor(CursorIcon::ResizeHorizontal);
+            if response.double_clicked() {
+                self.preview_split = DEFAULT_PREVIEW_SPLIT;
+            } else if response.dragged() {
+                self.preview_split += response.drag_delta().x / panes_width;
+            }
+            let stroke = if response.hovered() || response.dragged() {
+                ui.visuals().widgets.hovered.fg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            ui.painter().vline(rect.center().x, rect.y_range(), stroke);
+            let preview_width = panes_width - editor_width;
+            ui.allocate_ui_with_layout(vec2(preview_width, EDITOR_HEIGHT), layout, |ui| {
+                ui.set_width(preview_width);
+                self.preview_ui(ui);
+            });
+        });
+    }
+
+    fn preview_ui(&mut self, ui: &mut egui::Ui) {
+        let markdown = blog::split_on_line_breaks(&self.content)
+            .iter()
+            .map(|line| blog::autolink(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let style = css::PreviewStyle::from_css(&self.page.css);
+        egui::Frame::new()
+            .fill(style.background.unwrap_or(egui::Color32::TRANSPARENT))
+            .inner_margin(4)
+            .show(ui, |ui| {
+                style.apply(ui);
+                egui::ScrollArea::vertical()
+                    .id_salt("preview")
+                    .max_height(EDITOR_HEIGHT)
+                    .show(ui, |ui| {
+                        // Framed like the exported page, with the site title and footer links:
+                        if !self.page.title.is_empty() {
+                            ui.heading(&self.page.title);
+                            ui.separator();
+                        }
+                        // Fenced code blocks are highlighted by the language after the ```
+                        // (see the `better_syntax_highlighting` feature), unknown languages
+                        // stay plain monospace:
+                        CommonMarkViewer::new().show(ui, &mut self.markdown_cache, &markdown);
+                        if !self.footer_links.is_empty() {
+                            ui.separator();
+                            ui.horizontal_wrapped(|ui| {
+                                for link in &self.footer_links {
+                                    ui.hyperlink_to(&link.label, &link.url);
+                                }
+                            });
+                        }
+                    });
+            });
+    }
+
+    fn encryption_settings_ui(&mut self, ui: &mut egui::Ui, posts_file: &Path) {
+        let t = |key| i18n::tr(self.language, key);
+        ui.heading(t("encryption"));
+        let change = if self.blog_posts.is_encrypted() {
+            ui.label(t("file_is_encrypted"));
+            ui.button(t("remove_encryption")).clicked().then_some(None)
+        } else {
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.new_passphrase)
+                        .password(true)
+                        .hint_text(t("passphrase")),
+                );
+                ui.add_enabled(
+                    !self.new_passphrase.is_empty(),
+                    egui::Button::new(t("encrypt")),
+                )
+                .on_hover_text(t("encrypt_hover"))
+                .clicked()
+                .then(|| Some(std::mem::take(&mut self.new_passphrase)))
             })
-            .with_decorations(false) // Hide the OS-specific "chrome" around the window
-            .with_inner_size([800.0, 400.0])
-            .with_min_inner_size([800.0, 400.0])
-            .with_transparent(true), // To have rounded corners we need transparency
+            .inner
+        };
+        if let Some(passphrase) = change {
+            let encrypted = passphrase.is_some();
+            match blog::set_passphrase(passphrase, &mut self.blog_posts, posts_file) {
+                Ok(_) if encrypted => {
+                    self.result_text = t("posts_file_encrypted").to_string();
+                    // The autosaved draft would be a plain-text copy:
+                    Draft::clear(posts_file);
+                }
+                Ok(_) => self.result_text = t("posts_file_decrypted").to_string(),
+                Err(e) => self.show_error(e),
+            }
+        }
+    }
 
-        ..Default::default()
-    };
-    eframe::run_native(
-        "Custom window frame", // unused title
-        options,
-        Box::new(|_cc| Ok(Box::<MyApp>::default())),
-    )
-}
+    fn stats_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let stats = self.blog_posts.stats();
+        egui::Window::new(t("stats"))
+            .open(&mut self.show_stats)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(i18n::tr_args(
+                    self.language,
+                    "total_posts",
+                    &[&stats.total_posts],
+                ));
+                ui.separator();
+                ui.heading(t("per_month"));
+                egui::Grid::new("posts_per_month").show(ui, |ui| {
+                    // Newest month first, with the unknown ones last:
+                    let (unknown, months): (Vec<_>, Vec<_>) = stats
+                        .posts_per_month
+                        .iter()
+                        .partition(|(month, _)| month.as_str() == blog::UNKNOWN_MONTH);
+                    for (month, count) in months.into_iter().rev().chain(unknown) {
+                        ui.label(month);
+                        ui.label(count.to_string());
+                        ui.end_row();
+                    }
+                });
+                if !stats.tag_counts.is_empty() {
+                    ui.separator();
+                    ui.heading(t("tags_heading"));
+                    ui.horizontal_wrapped(|ui| {
+                        for (tag, count) in &stats.tag_counts {
+                            ui.label(format!("{} ({})", tag, count));
+                        }
+                    });
+                }
+            });
+    }
 
-struct MyApp {
-    result_text: String,
-    title: String,
-    content: String,
-}
+    fn site_settings_ui(&mut self, ctx: &egui::Context) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        let Some(posts_file) = self.posts_file.clone() else {
+            return;
+        };
+        let mut open = self.show_site_settings;
+        egui::Window::new(t("site_settings"))
+            .open(&mut open)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                if self.busy() {
+                    ui.disable();
+                }
+                ui.heading(t("page"));
+                ui.label(t("title"));
+                ui.text_edit_singleline(&mut self.page.title);
+                ui.label(t("site_url")).on_hover_text(t("site_url_hover"));
+                let mut site_url = self.page.site_url.clone().unwrap_or_default();
+                ui.add(egui::TextEdit::singleline(&mut site_url).hint_text("https://example.com"));
+                self.page.site_url = (!site_url.trim().is_empty()).then_some(site_url);
+                ui.label(t("timestamp_format"))
+                    .on_hover_text(t("timestamp_format_hover"));
+                ui.text_edit_singleline(&mut self.page.time_format);
+                if let Err(e) = self.page.validate() {
+                    ui.colored_label(ui.visuals().warn_fg_color, e.to_string());
+                }
+                ui.horizontal(|ui| {
+                    let mut limited = self.page.max_words.is_some();
+                    ui.checkbox(&mut limited, t("limit_posts_to"));
+                    let mut max_words = self.page.max_words.unwrap_or(DEFAULT_MAX_WORDS);
+                    ui.add_enabled(
+                        limited,
+                        egui::DragValue::new(&mut max_words)
+                            .range(1..=100_000)
+                            .suffix(t("words_suffix")),
+                    );
+                    self.page.max_words = limited.then_some(max_words);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("keep"));
+                    ui.add(egui::DragValue::new(&mut self.page.max_history).range(0..=100));
+                    ui.label(t("earlier_versions"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("keep"));
+                    ui.add(egui::DragValue::new(&mut self.page.max_backups).range(0..=100));
+                    ui.label(t("backups"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("html_template"))
+                        .on_hover_text(t("template_hover"));
+                    let mut template = self.page.template.clone().unwrap_or_default();
+                    ui.add(egui::TextEdit::singleline(&mut template).hint_text(t("built_in")));
+                    if ui.button(t("choose")).clicked()
+                        && let Some(path) = rfd::FileDialog::new()
+                            .add_filter("HTML", &["html", "htm"])
+                            .pick_file()
+                    {
+                        template = path.display().to_string();
+                    }
+                    self.page.template = (!template.trim().is_empty()).then_some(template);
+                });
+                ui.label(t("css"));
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.page.css)
+                        .font(egui::TextStyle::Monospace)
+                        .desired_rows(6),
+                );
+                ui.separator();
+                ui.heading(t("footer_links"));
+                let mut changed = false;
+                let mut removed = None;
+                let mut moved = None;
+                egui::Grid::new("footer_links")
+                    .num_columns(4)
+                    .show(ui, |ui| {
+                        for (index, link) in self.footer_links.iter_mut().enumerate() {
+                            let handle_response = ui
+                                .dnd_drag_source(
+                                    egui::Id::new(("footer_link_drag", index)),
+                                    FooterLinkDrag(index),
+                                    |ui| ui.label("☰"),
+                                )
+                                .response
+                                .on_hover_text(t("drag_to_reorder"));
+                            let label_response = ui.add(
+                                egui::TextEdit::singleline(&mut link.label).hint_text(t("label")),
+                            );
+                            let url_response =
+                                ui.add(egui::TextEdit::singleline(&mut link.url).hint_text("URL"));
+                            changed |= label_response.lost_focus() || url_response.lost_focus();
+                            let row_rect = handle_response.rect.union(url_response.rect);
+                            let row_response = ui.interact(
+                                row_rect,
+                                egui::Id::new(("footer_link_drop", index)),
+                                egui::Sense::hover(),
+                            );
+                            if row_response.dnd_hover_payload::<FooterLinkDrag>().is_some() {
+                                ui.painter().hline(
+                                    row_rect.x_range(),
+                                    row_rect.top(),
+                                    ui.visuals().selection.stroke,
+                                );
+                            }
+                            if let Some(from) = row_response.dnd_release_payload::<FooterLinkDrag>()
+                            {
+                                moved = Some((from.0, index));
+                            }
+                            if ui
+                                .small_button("🗑")
+                                .on_hover_text(t("delete_link_hover"))
+                                .clicked()
+                            {
+                                removed = Some(index);
+                            }
+                            ui.end_row();
+                            if let Some(warning) = link.url_warning() {
+                                ui.colored_label(ui.visuals().warn_fg_color, warning);
+                                ui.end_row();
+                            }
+                        }
+                    });
+                if let Some(index) = removed {
+                    self.footer_links.remove(index);
+                    changed = true;
+                }
+                if let Some((from, to)) = moved
+                    && from != to
+                {
+                    let link = self.footer_links.remove(from);
+                    self.footer_links.insert(to, link);
+                    changed = true;
+                }
+                if ui.button(t("add_link")).clicked() {
+                    self.footer_links.push(blog::FooterLink::default());
+                }
+                let all_valid = self.footer_links.iter().all(|link| link.validate().is_ok());
+                if changed && all_valid {
+                    match blog::save_footer_links(
+                        self.footer_links.clone(),
+                        &mut self.blog_posts,
+                        &posts_file,
+                    ) {
+                        Ok(_) => self.result_text = t("footer_links_saved").to_string(),
+                        Err(e) => self.show_error(e),
+                    }
+                }
+                ui.separator();
+                self.encryption_settings_ui(ui, &posts_file);
+                ui.separator();
+                ui.heading(t("publishing"));
+                ui.horizontal(|ui| {
+                    ui.label(t("warn_larger_than"));
+                    ui.add(
+                        egui::DragValue::new(&mut self.post_size_warning_kb)
+                            .range(1..=10_000)
+                            .suffix(" KB"),
+                    );
+                });
+                ui.checkbox(&mut self.confirm_publish, t("show_changes_before_publish"));
+                if ui
+                    .checkbox(&mut self.pretty_json, t("pretty_json"))
+                    .on_hover_text(t("pretty_json_hover"))
+                    .changed()
+                {
+                    self.blog_posts.set_pretty_json(self.pretty_json);
+                }
+                ui.checkbox(&mut self.autolink_on_publish, t("autolink"))
+                    .on_hover_text(t("autolink_hover"));
+                ui.checkbox(&mut self.log_actions, t("log_actions"))
+                    .on_hover_text(t("log_actions_hover"));
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.date_titles, t("date_titles"));
+                    ui.add_enabled(
+                        self.date_titles,
+                        egui::TextEdit::singleline(&mut self.date_title_format)
+                            .desired_width(120.0),
+                    )
+                    .on_hover_text(t("date_format_hover"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("publish_to"));
+                    ui.radio_value(
+                        &mut self.publish_target,
+                        blog::PublishTarget::File,
+                        t("posts_file_target"),
+                    );
+                    let to_http = matches!(self.publish_target, blog::PublishTarget::Http(_));
+                    if ui.radio(to_http, t("http_endpoint")).clicked() && !to_http {
+                        self.publish_target = blog::PublishTarget::Http(String::new());
+                    }
+                });
+                if let blog::PublishTarget::Http(url) = &mut self.publish_target {
+                    ui.add(egui::TextEdit::singleline(url).hint_text("https://example.com/posts"))
+                        .on_hover_text(t("http_endpoint_hover"));
+                }
+                ui.separator();
+                if ui
+                    .button(t("save"))
+                    .on_hover_text(i18n::tr_args(
+                        language,
+                        "save_settings_hover",
+                        &[&ui.ctx().format_shortcut(&SAVE_SHORTCUT)],
+                    ))
+                    .clicked()
+                {
+                    self.save_settings();
+                }
+            });
+        self.show_site_settings = open;
+    }
 
-impl Default for MyApp {
-    fn default() -> Self {
-        Self {
-            result_text: "".to_owned(),
-            title: "Today's Entry".to_owned(),
-            content: "Something happend today".to_owned(),
+    fn new_site_title_ui(&mut self, ctx: &egui::Context) {
+        let t = |key| i18n::tr(self.language, key);
+        let (Some(mut title), Some(posts_file)) =
+            (self.new_site_title.take(), self.posts_file.clone())
+        else {
+            return;
+        };
+        let mut confirmed = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("new_site_title")).show(ctx, |ui| {
+            ui.label(t("new_site_prompt"));
+            ui.text_edit_singleline(&mut title);
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button(t("create")).clicked() {
+                    confirmed = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if confirmed {
+            let page = blog::Page {
+                title,
+                ..Default::default()
+            };
+            match blog::save_page(page, &mut self.blog_posts, &posts_file) {
+                Ok(_) => {
+                    self.page = self.blog_posts.page().clone();
+                    self.publish_current(Some(&posts_file));
+                }
+                Err(e) => self.show_error(e),
+            }
+        } else if !cancelled && !modal.should_close() {
+            self.new_site_title = Some(title);
+        }
+    }
+
+    fn delete_confirmation_ui(&mut self, ctx: &egui::Context) {
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        let busy = self.busy();
+        let (Some(index), Some(posts_file)) = (self.pending_delete, self.posts_file.clone()) else {
+            return;
+        };
+        let title = self
+            .blog_posts
+            .posts()
+            .get(index)
+            .map(|post| post.title.clone())
+            .unwrap_or_default();
+        let mut confirmed = false;
+        let mut cancelled = false;
+        let modal = egui::Modal::new(egui::Id::new("delete_confirmation")).show(ctx, |ui| {
+            ui.label(i18n::tr_args(language, "delete_prompt", &[&title]));
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!busy, egui::Button::new(t("delete")))
+                    .on_disabled_hover_text(t("saving"))
+                    .clicked()
+                {
+                    confirmed = true;
+                }
+                if ui.button(t("cancel")).clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+        if confirmed {
+            let before = self.blog_posts.clone();
+            let result = blog::delete(index, &mut self.blog_posts, &posts_file);
+            self.log_action(&posts_file, "delete", &title, result.as_ref().err());
+            match result {
+                Ok(_) => {
+                    self.record_undo("delete", before);
+                    self.result_text = t("delete_successful").to_string();
+                    self.selected_post = match self.selected_post {
+                        Some(selected) if selected == index => None,
+                        Some(selected) if selected > index => Some(selected - 1),
+                        selected => selected,
+                    };
+                }
+                Err(e) => self.show_error(e),
+            }
+        }
+        if confirmed || cancelled || modal.should_close() {
+            self.pending_delete = None;
         }
     }
 }
+
 impl eframe::App for MyApp {
     fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
         egui::Rgba::TRANSPARENT.to_array() // Make sure we don't paint anything behind the rounded corners
     }
 
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, WINDOW_KEY, &self.window_state);
+        let draft = if self.blog_posts.is_encrypted() {
+            Draft::default()
+        } else {
+            self.current_draft()
+        };
+        eframe::set_value(storage, DRAFT_KEY, &draft);
+        eframe::set_value(storage, THEME_KEY, &self.theme);
+        let config = self.current_config();
+        if config != self.config {
+            config.save();
+            self.config = config;
+        }
+        eframe::set_value(storage, RECENT_FILES_KEY, &self.recent_files);
+        eframe::set_value(storage, POST_SIZE_WARNING_KEY, &self.post_size_warning_kb);
+        eframe::set_value(storage, EXPORT_FUTURE_POSTS_KEY, &self.export_future_posts);
+        eframe::set_value(storage, SOFT_WRAP_KEY, &self.soft_wrap);
+        eframe::set_value(storage, LINE_NUMBERS_KEY, &self.show_line_numbers);
+        eframe::set_value(storage, ZEN_MODE_KEY, &self.zen_mode);
+        eframe::set_value(storage, POSTS_PER_PAGE_KEY, &self.posts_per_page);
+        eframe::set_value(storage, LAST_EXPORT_DIR_KEY, &self.last_export_dir);
+        eframe::set_value(storage, EDITOR_FONT_KEY, &self.editor_font);
+        eframe::set_value(storage, CUSTOM_FONT_KEY, &self.custom_font);
+        eframe::set_value(storage, EDITOR_FONT_SIZE_KEY, &self.editor_font_size);
+        eframe::set_value(storage, PRETTY_JSON_KEY, &self.pretty_json);
+        eframe::set_value(storage, POST_ORDER_KEY, &self.post_order);
+        eframe::set_value(storage, AUTOLINK_ON_PUBLISH_KEY, &self.autolink_on_publish);
+        eframe::set_value(storage, TAB_INDENTS_KEY, &self.tab_indents);
+        eframe::set_value(storage, INDENT_KEY, &self.indent);
+        eframe::set_value(storage, HIGHLIGHT_OVERUSED_KEY, &self.highlight_overused);
+        eframe::set_value(storage, OVERUSE_THRESHOLD_KEY, &self.overuse_threshold);
+        eframe::set_value(storage, STOPWORDS_KEY, &self.stopwords);
+        eframe::set_value(storage, LOG_ACTIONS_KEY, &self.log_actions);
+        eframe::set_value(storage, DENSITY_KEY, &self.density);
+        eframe::set_value(storage, DATE_TITLES_KEY, &self.date_titles);
+        eframe::set_value(storage, DATE_TITLE_FORMAT_KEY, &self.date_title_format);
+        eframe::set_value(storage, PUBLISH_TARGET_KEY, &self.publish_target);
+        eframe::set_value(storage, SPELL_CHECK_KEY, &self.spell_check);
+        eframe::set_value(
+            storage,
+            SPELL_CHECK_LANGUAGE_KEY,
+            &self.spell_check_language,
+        );
+        eframe::set_value(storage, CONFIRM_PUBLISH_KEY, &self.confirm_publish);
+        eframe::set_value(storage, LANGUAGE_KEY, &self.language);
+        eframe::set_value(storage, MINIMIZE_TO_TRAY_KEY, &self.minimize_to_tray);
+        eframe::set_value(storage, PREVIEW_SPLIT_KEY, &self.preview_split);
+        eframe::set_value(storage, BLOGS_KEY, &self.blogs);
+        eframe::set_value(storage, ACTIVE_BLOG_KEY, &self.active_blog);
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        custom_window_frame(ctx, "Wizard Of Arc Blog Entry Maker", |ui| {
-            ui.heading("Enter Title");
-            ui.text_edit_singleline(&mut self.title);
+        if !self.theme_applied {
+            ctx.set_theme(self.theme);
+            self.theme_applied = true;
+        }
+        if self.applied_density != Some(self.density) {
+            self.density.apply(ctx);
+            self.applied_density = Some(self.density);
+        }
+        self.window_state.track(ctx);
+        self.sync_tray(ctx);
+        self.handle_close_request(ctx);
+        self.handle_dropped_files(ctx);
+        self.autosave_draft(ctx);
+        self.handle_history_shortcuts(ctx);
+        self.handle_indent_keys(ctx);
+        self.handle_image_paste(ctx);
+        self.handle_publish_shortcut(ctx);
+        self.handle_save_shortcut(ctx);
+        self.handle_find_replace_shortcut(ctx);
+        self.handle_zen_mode_shortcut(ctx);
+        self.handle_font_size_shortcuts(ctx);
+        self.handle_focus_content(ctx);
+        self.finish_publish(ctx);
+        self.poll_bulk_operation(ctx);
+        let posts_file = self.posts_file.clone();
+        let dirty = self.has_unsaved_content();
+        let language = self.language;
+        let t = |key| i18n::tr(language, key);
+        let title = t("app_title");
+        let file = posts_file.as_deref();
+        let tray = self.tray.is_some();
+        custom_window_frame(ctx, title, file, dirty, language, tray, |title_bar, ui| {
+            // The title bar itself stays, so the window can still be dragged:
+            if self.zen_mode {
+                self.zen_ui(ui);
+                return;
+            }
+            self.blog_switcher_ui(title_bar);
+            if self.pending_save.is_some() {
+                title_bar.spinner();
+                title_bar.label(t("saving"));
+            }
+            egui::TopBottomPanel::bottom("status_bar").show_inside(ui, |ui| self.status_bar_ui(ui));
+            egui::SidePanel::left("post_list")
+                .resizable(false)
+                .default_width(180.0)
+                .show_inside(ui, |ui| self.post_list_ui(ui));
+            self.error_banner_ui(ui);
+            ui.heading(t("enter_title"));
+            let title_response = with_error_border(ui, self.title_invalid, |ui| {
+                ui.text_edit_singleline(&mut self.title)
+            });
+            if title_response.changed() {
+                self.title_invalid = false;
+            }
             ui.add_space(20.0);
-            ui.heading("Enter Content");
-            let available_width = ui.available_width();
-            ui.add_sized(
-                Vec2::new(available_width, 200.0),
-                egui::TextEdit::multiline(&mut self.content),
-            );
-            ui.add_space(10.0);
-            if ui.button("Publish").clicked() {
-                let title = &self.title;
-                let post = blog::Post {
-                    title: title.to_string(),
-                    content: split_on_line_breaks(&self.content),
-                };
-                match blog::publish(post) {
-                    Ok(_) => self.result_text = "Publish successful".to_string(),
-                    Err(e) => self.result_text = e.to_string(),
+            ui.horizontal(|ui| {
+                ui.heading(t("enter_content"));
+                ui.checkbox(&mut self.show_preview, t("preview"));
+                ui.checkbox(&mut self.soft_wrap, t("wrap"));
+                ui.checkbox(&mut self.show_line_numbers, t("line_numbers"));
+                self.editor_font_ui(ui);
+                self.editor_font_size_ui(ui);
+                self.spell_check_ui(ui);
+                self.overuse_ui(ui);
+                self.indent_ui(ui);
+                if ui.button(t("zen")).on_hover_text(t("zen_hover")).clicked() {
+                    self.zen_mode = true;
                 }
-                self.title = "".to_string();
-                self.content = "".to_string();
+            });
+            self.content_toolbar_ui(ui);
+            self.find_replace_ui(ui);
+            if self.show_preview {
+                self.editor_and_preview_ui(ui);
+            } else {
+                self.content_editor_ui(ui, EDITOR_HEIGHT);
             }
-            ui.separator();
-            ui.label(self.result_text.clone());
+            self.changes_ui(ui);
+            ui.horizontal(|ui| {
+                ui.label(t("tags"));
+                ui.add(egui::TextEdit::singleline(&mut self.tags).hint_text(t("tags_hint")));
+                ui.checkbox(&mut self.published, t("published"))
+                    .on_hover_text(t("published_hover"));
+            });
+            ui.horizontal(|ui| self.scheduled_time_ui(ui));
+            ui.add_space(10.0);
+            let publish_label = match self.selected_post {
+                Some(_) => t("update"),
+                None => t("publish"),
+            };
+            let to_http = matches!(self.publish_target, blog::PublishTarget::Http(_));
+            ui.horizontal(|ui| {
+                let updating_over_http = to_http && self.selected_post.is_some();
+                let publish_response = ui
+                    .add_enabled(
+                        (self.posts_file.is_some() || to_http)
+                            && !self.busy()
+                            && !updating_over_http,
+                        egui::Button::new(publish_label),
+                    )
+                    .on_disabled_hover_text(if updating_over_http {
+                        t("no_http_update")
+                    } else if self.busy() {
+                        t("saving")
+                    } else {
+                        t("choose_posts_file_first")
+                    });
+                if publish_response.clicked() {
+                    self.request_publish();
+                }
+                if let Some(post) = self
+                    .selected_post
+                    .and_then(|index| self.blog_posts.posts().get(index))
+                    && ui
+                        .button(i18n::tr_args(
+                            language,
+                            "history_count",
+                            &[&post.history.len()],
+                        ))
+                        .on_hover_text(t("history_hover"))
+                        .clicked()
+                {
+                    self.post_history_version = None;
+                    self.show_post_history = true;
+                }
+                if ui
+                    .button(t("new_clear"))
+                    .on_hover_text(t("new_clear_hover"))
+                    .clicked()
+                {
+                    if self.has_unsaved_content() {
+                        self.show_clear_confirmation = true;
+                    } else {
+                        self.new_post();
+                    }
+                }
+                if let Some(undo) = &self.undo
+                    && ui
+                        .add_enabled(!self.busy(), egui::Button::new(t("undo_last_action")))
+                        .on_hover_text(i18n::tr_args(
+                            language,
+                            "undo_action",
+                            &[&action_name(language, undo.action)],
+                        ))
+                        .clicked()
+                {
+                    self.undo_last_action();
+                }
+            });
             ui.separator();
             ui.horizontal(|ui| {
-                ui.label("Theme:");
+                ui.label(t("theme"));
                 egui::widgets::global_theme_preference_buttons(ui);
+                ui.separator();
+                ui.label(t("language"));
+                egui::ComboBox::from_id_salt("language")
+                    .selected_text(self.language.name())
+                    .show_ui(ui, |ui| {
+                        for language in i18n::Language::ALL {
+                            ui.selectable_value(&mut self.language, language, language.name());
+                        }
+                    });
+                ui.separator();
+                ui.label(t("density"));
+                for density in density::Density::ALL {
+                    ui.selectable_value(&mut self.density, density, t(density.label_key()));
+                }
+                ui.separator();
+                ui.checkbox(&mut self.minimize_to_tray, t("minimize_to_tray"));
             });
+            self.theme = ui.ctx().options(|options| options.theme_preference);
         });
+        self.toast_ui(ctx);
+        self.site_settings_ui(ctx);
+        self.stats_ui(ctx);
+        self.log_ui(ctx);
+        self.post_history_ui(ctx);
+        self.draft_restore_ui(ctx);
+        self.new_site_title_ui(ctx);
+        self.delete_confirmation_ui(ctx);
+        self.reload_prompt_ui(ctx);
+        self.passphrase_prompt_ui(ctx);
+        self.close_confirmation_ui(ctx);
+        self.blog_switch_confirmation_ui(ctx);
+        self.clear_confirmation_ui(ctx);
+        self.duplicate_title_confirmation_ui(ctx);
+        self.publish_plan_confirmation_ui(ctx);
+        self.bulk_operation_ui(ctx);
+    }
+}
+
+/// Paint the number of each line left of the `TextEdit` in `output`, next to the row the
+/// line starts on so wrapped lines get a single number.
+fn line_numbers_ui(
+    ui: &egui::Ui,
+    output: &egui::text_edit::TextEditOutput,
+    font_id: &egui::FontId,
+) {
+    let x = output.response.rect.left() - 4.0;
+    let mut line = 1;
+    let mut starts_line = true;
+    for row in &output.galley.rows {
+        if starts_line {
+            ui.painter().text(
+                egui::pos2(x, output.galley_pos.y + row.pos.y),
+                egui::Align2::RIGHT_TOP,
+                line.to_string(),
+                font_id.clone(),
+                ui.visuals().weak_text_color(),
+            );
+            line += 1;
+        }
+        starts_line = row.ends_with_newline;
+    }
+}
+
+/// Lay out `text` with the `underlined` byte ranges underlined, as misspelled, and the
+/// `highlighted` ones given a faint background. Both must be in order.
+fn marked_layout_job(
+    ui: &egui::Ui,
+    text: &str,
+    underlined: &[std::ops::Range<usize>],
+    highlighted: &[std::ops::Range<usize>],
+    font_id: egui::FontId,
+) -> egui::text::LayoutJob {
+    let mut job = egui::text::LayoutJob::default();
+    let plain = egui::TextFormat::simple(font_id, ui.visuals().widgets.inactive.text_color());
+    let underline = egui::Stroke::new(1.0, ui.visuals().error_fg_color);
+    let background = ui.visuals().warn_fg_color.gamma_multiply(0.25);
+    // Split the text wherever a mark starts or ends, then format each piece by its marks:
+    let mut bounds: Vec<usize> = underlined
+        .iter()
+        .chain(highlighted)
+        .flat_map(|range| [range.start, range.end])
+        .chain([0, text.len()])
+        .collect();
+    bounds.sort_unstable();
+    bounds.dedup();
+    let (mut next_underlined, mut next_highlighted) = (0, 0);
+    for piece in bounds.windows(2) {
+        let (start, end) = (piece[0], piece[1]);
+        let marked = |ranges: &[std::ops::Range<usize>], next: &mut usize| {
+            while ranges.get(*next).is_some_and(|range| range.end <= start) {
+                *next += 1;
+            }
+            ranges
+                .get(*next)
+                .is_some_and(|range| range.start <= start && end <= range.end)
+        };
+        let mut format = plain.clone();
+        if marked(underlined, &mut next_underlined) {
+            format.underline = underline;
+        }
+        if marked(highlighted, &mut next_highlighted) {
+            format.background = background;
+        }
+        job.append(&text[start..end], 0.0, format);
+    }
+    job
+}
+
+/// Paint the border of the widget(s) added by `add_contents` red when `invalid` is set.
+fn with_error_border<R>(
+    ui: &mut egui::Ui,
+    invalid: bool,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    ui.scope(|ui| {
+        if invalid {
+            let stroke = egui::Stroke::new(1.0, ui.visuals().error_fg_color);
+            let visuals = ui.visuals_mut();
+            visuals.widgets.inactive.bg_stroke = stroke;
+            visuals.widgets.hovered.bg_stroke = stroke;
+            visuals.selection.stroke = stroke;
+        }
+        add_contents(ui)
+    })
+    .inner
+}
+
+/// Lay out `text` with every case-insensitive occurrence of `query` highlighted.
+fn highlight_matches(ui: &egui::Ui, text: &str, query: &str) -> egui::text::LayoutJob {
+    use egui::text::{LayoutJob, TextFormat};
+
+    let format = TextFormat {
+        font_id: egui::TextStyle::Button.resolve(ui.style()),
+        color: ui.visuals().text_color(),
+        ..Default::default()
+    };
+    let highlighted = TextFormat {
+        background: ui.visuals().selection.bg_fill,
+        ..format.clone()
+    };
+    let mut job = LayoutJob::default();
+    let lower_text = text.to_lowercase();
+    let lower_query = query.to_lowercase();
+    // Lowercasing can change byte offsets for some scripts, in which case don't highlight:
+    if lower_query.is_empty() || lower_text.len() != text.len() {
+        job.append(text, 0.0, format);
+        return job;
+    }
+    let mut start = 0;
+    for (found, _) in lower_text.match_indices(&lower_query) {
+        let end = found + lower_query.len();
+        if !text.is_char_boundary(found) || !text.is_char_boundary(end) {
+            continue;
+        }
+        job.append(&text[start..found], 0.0, format.clone());
+        job.append(&text[found..end], 0.0, highlighted.clone());
+        start = end;
+    }
+    job.append(&text[start..], 0.0, format);
+    job
+}
+
+/// The name the undo messages give `action`, as passed to `record_undo`.
+fn action_name(language: i18n::Language, action: &str) -> &'static str {
+    let key = match action {
+        "update" => "action_update",
+        "delete" => "action_delete",
+        _ => "action_publish",
+    };
+    i18n::tr(language, key)
+}
+
+/// `time` in full, the way the system locale writes dates and times.
+fn absolute_time(time: DateTime<Local>) -> String {
+    let locale = sys_locale::get_locale()
+        .and_then(|name| chrono::Locale::try_from(name.replace('-', "_").as_str()).ok())
+        .unwrap_or(chrono::Locale::POSIX);
+    time.format_localized("%c", locale).to_string()
+}
+
+/// `time` relative to now, with the full date and time on hover.
+fn relative_time_label(ui: &mut egui::Ui, time: DateTime<Local>) -> egui::Response {
+    ui.weak(blog::relative_time(time, Local::now()))
+        .on_hover_text(absolute_time(time))
+}
+
+fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = vec![];
+    for tag in input
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+    {
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
     }
+    tags
+}
+
+/// Open the system file manager at `path`, with it selected where the platform allows.
+fn reveal_in_file_manager(path: &Path) -> std::io::Result<()> {
+    use std::process::Command;
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("explorer");
+        command.arg(format!("/select,{}", path.display()));
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = Command::new("open");
+        command.arg("-R").arg(path);
+        command
+    };
+    // There's no common way to select a file, so open the folder holding it:
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = {
+        let mut command = Command::new("xdg-open");
+        let folder = path
+            .parent()
+            .filter(|folder| !folder.as_os_str().is_empty());
+        command.arg(folder.unwrap_or(Path::new(".")));
+        command
+    };
+    command.spawn().map(|_| ())
 }
 
-fn split_on_line_breaks(content: &str) -> Vec<String> {
-    let strip_r = content.replace("\r", "");
-    strip_r.split("\n").map(|s| s.to_string()).collect()
+/// The drag-and-drop payload for reordering footer links: the index being dragged.
+struct FooterLinkDrag(usize);
+
+/// `posts.json* — Wizard Of Arc Blog Entry Maker`, with the asterisk when `dirty`.
+fn window_title(title: &str, posts_file: Option<&Path>, dirty: bool) -> String {
+    let marker = if dirty { "*" } else { "" };
+    match posts_file.and_then(Path::file_name) {
+        Some(file_name) => format!("{}{} — {}", file_name.to_string_lossy(), marker, title),
+        None => format!("{}{}", title, marker),
+    }
 }
 
-fn custom_window_frame(ctx: &egui::Context, title: &str, add_contents: impl FnOnce(&mut egui::Ui)) {
+/// `add_contents` gets a `Ui` on the left of the title bar and one for the window contents.
+/// With `minimize_to_tray` the minimize button hides the window instead.
+fn custom_window_frame(
+    ctx: &egui::Context,
+    title: &str,
+    posts_file: Option<&Path>,
+    dirty: bool,
+    language: i18n::Language,
+    minimize_to_tray: bool,
+    add_contents: impl FnOnce(&mut egui::Ui, &mut egui::Ui),
+) {
     use egui::{CentralPanel, UiBuilder};
 
     let panel_frame = egui::Frame::new()
@@ -113,7 +3952,15 @@ fn custom_window_frame(ctx: &egui::Context, title: &str, add_contents: impl FnOn
             rect.max.y = rect.min.y + title_bar_height;
             rect
         };
-        title_bar_ui(ui, title_bar_rect, title);
+        title_bar_ui(
+            ui,
+            title_bar_rect,
+            title,
+            posts_file,
+            dirty,
+            language,
+            minimize_to_tray,
+        );
 
         // Add the contents:
         let content_rect = {
@@ -122,12 +3969,25 @@ fn custom_window_frame(ctx: &egui::Context, title: &str, add_contents: impl FnOn
             rect
         }
         .shrink(4.0);
+        let mut title_bar_contents_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(title_bar_rect.shrink2(egui::vec2(8.0, 0.0)))
+                .layout(egui::Layout::left_to_right(egui::Align::Center)),
+        );
         let mut content_ui = ui.new_child(UiBuilder::new().max_rect(content_rect));
-        add_contents(&mut content_ui);
+        add_contents(&mut title_bar_contents_ui, &mut content_ui);
     });
 }
 
-fn title_bar_ui(ui: &mut egui::Ui, title_bar_rect: eframe::epaint::Rect, title: &str) {
+fn title_bar_ui(
+    ui: &mut egui::Ui,
+    title_bar_rect: eframe::epaint::Rect,
+    title: &str,
+    posts_file: Option<&Path>,
+    dirty: bool,
+    language: i18n::Language,
+    minimize_to_tray: bool,
+) {
     use egui::{Align2, FontId, Id, PointerButton, Sense, UiBuilder, vec2};
 
     let painter = ui.painter();
@@ -142,7 +4002,7 @@ fn title_bar_ui(ui: &mut egui::Ui, title_bar_rect: eframe::epaint::Rect, title:
     painter.text(
         title_bar_rect.center(),
         Align2::CENTER_CENTER,
-        title,
+        window_title(title, posts_file, dirty),
         FontId::proportional(20.0),
         ui.style().visuals.text_color(),
     );
@@ -175,46 +4035,117 @@ fn title_bar_ui(ui: &mut egui::Ui, title_bar_rect: eframe::epaint::Rect, title:
             ui.spacing_mut().item_spacing.x = 0.0;
             ui.visuals_mut().button_frame = false;
             ui.add_space(8.0);
-            close_maximize_minimize(ui);
+            close_maximize_minimize(ui, language, minimize_to_tray);
         },
     );
 }
 
 /// Show some close/maximize/minimize buttons for the native window.
-fn close_maximize_minimize(ui: &mut egui::Ui) {
-    use egui::{Button, RichText};
-
-    let button_height = 12.0;
-
-    let close_response = ui
-        .add(Button::new(RichText::new("❌").size(button_height)))
-        .on_hover_text("Close the window");
+fn close_maximize_minimize(ui: &mut egui::Ui, language: i18n::Language, minimize_to_tray: bool) {
+    let close_response = window_button(ui, WindowIcon::Close, i18n::tr(language, "close_window"));
     if close_response.clicked() {
         ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
     }
 
     let is_maximized = ui.input(|i| i.viewport().maximized.unwrap_or(false));
     if is_maximized {
-        let maximized_response = ui
-            .add(Button::new(RichText::new("🗗").size(button_height)))
-            .on_hover_text("Restore window");
+        let maximized_response = window_button(
+            ui,
+            WindowIcon::Restore,
+            i18n::tr(language, "restore_window"),
+        );
         if maximized_response.clicked() {
             ui.ctx()
                 .send_viewport_cmd(ViewportCommand::Maximized(false));
         }
     } else {
-        let maximized_response = ui
-            .add(Button::new(RichText::new("🗗").size(button_height)))
-            .on_hover_text("Maximize window");
+        let maximized_response = window_button(
+            ui,
+            WindowIcon::Maximize,
+            i18n::tr(language, "maximize_window"),
+        );
         if maximized_response.clicked() {
             ui.ctx().send_viewport_cmd(ViewportCommand::Maximized(true));
         }
     }
 
-    let minimized_response = ui
-        .add(Button::new(RichText::new("🗕").size(button_height)))
-        .on_hover_text("Minimize the window");
+    let minimized_response = window_button(
+        ui,
+        WindowIcon::Minimize,
+        i18n::tr(language, "minimize_window"),
+    );
     if minimized_response.clicked() {
-        ui.ctx().send_viewport_cmd(ViewportCommand::Minimized(true));
+        if minimize_to_tray {
+            tray::hide_window(ui.ctx());
+        } else {
+            ui.ctx().send_viewport_cmd(ViewportCommand::Minimized(true));
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum WindowIcon {
+    Close,
+    Maximize,
+    Restore,
+    Minimize,
+}
+
+/// A title bar button with its icon drawn in the theme's colors, so it looks the same on
+/// every platform and follows high-contrast themes. It can be reached with Tab and pressed
+/// with Enter or Space like any other egui button.
+fn window_button(ui: &mut egui::Ui, icon: WindowIcon, label: &str) -> egui::Response {
+    use egui::{Rect, Sense, Stroke, StrokeKind, WidgetInfo, WidgetType, vec2};
+
+    let size = ui.spacing().interact_size.y;
+    let (rect, response) = ui.allocate_exact_size(vec2(size, size), Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label));
+    let response = response.on_hover_text(label);
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
+    let visuals = ui.style().interact(&response);
+    let painter = ui.painter();
+    let background = if response.hovered() || response.has_focus() {
+        painter.rect_filled(rect, visuals.corner_radius, visuals.bg_fill);
+        visuals.bg_fill
+    } else {
+        ui.visuals().window_fill()
+    };
+    if response.has_focus() {
+        painter.rect_stroke(
+            rect,
+            visuals.corner_radius,
+            ui.visuals().selection.stroke,
+            StrokeKind::Inside,
+        );
+    }
+    let stroke = Stroke::new(1.5, visuals.fg_stroke.color);
+    let icon_rect = Rect::from_center_size(rect.center(), vec2(size, size) * 0.4);
+    match icon {
+        WindowIcon::Close => {
+            painter.line_segment([icon_rect.left_top(), icon_rect.right_bottom()], stroke);
+            painter.line_segment([icon_rect.right_top(), icon_rect.left_bottom()], stroke);
+        }
+        WindowIcon::Maximize => {
+            painter.rect_stroke(icon_rect, 0.0, stroke, StrokeKind::Middle);
+        }
+        WindowIcon::Restore => {
+            let offset = icon_rect.width() * 0.25;
+            let back = icon_rect
+                .translate(vec2(offset, -offset))
+                .shrink(offset * 0.5);
+            let front = icon_rect
+                .translate(vec2(-offset, offset))
+                .shrink(offset * 0.5);
+            painter.rect_stroke(back, 0.0, stroke, StrokeKind::Middle);
+            // Covers the back window where they overlap:
+            painter.rect_filled(front, 0.0, background);
+            painter.rect_stroke(front, 0.0, stroke, StrokeKind::Middle);
+        }
+        WindowIcon::Minimize => {
+            painter.hline(icon_rect.x_range(), icon_rect.bottom(), stroke);
+        }
     }
+    response
 }
@@ -0,0 +1,59 @@
+//! Highlighting the words the content editor's text leans on too often, as a writing aid.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::spelling;
+
+/// How many times a word can be used before it's highlighted.
+pub const DEFAULT_THRESHOLD: usize = 4;
+/// Words too common to be worth highlighting, however often they're used.
+pub const DEFAULT_STOPWORDS: &str = "a an and are as at be but by for from had has have he her \
+his i if in is it its me my no not of on or our she so than that the their them then there \
+they this to too was we were what when which who will with would you your";
+
+/// Remembers the last text it looked at, since the layouter asks every frame.
+#[derive(Default)]
+pub struct OveruseChecker {
+    checked: String,
+    threshold: usize,
+    stopwords: String,
+    overused: Vec<Range<usize>>,
+}
+
+impl OveruseChecker {
+    /// The byte ranges of the words in `text` used more than `threshold` times, ignoring
+    /// case and the whitespace-separated `stopwords`.
+    pub fn overused(&mut self, text: &str, threshold: usize, stopwords: &str) -> &[Range<usize>] {
+        if text != self.checked || threshold != self.threshold || stopwords != self.stopwords {
+            self.overused = overused_words(text, threshold, stopwords);
+            self.checked = text.to_owned();
+            self.threshold = threshold;
+            self.stopwords = stopwords.to_owned();
+        }
+        &self.overused
+    }
+}
+
+fn overused_words(text: &str, threshold: usize, stopwords: &str) -> Vec<Range<usize>> {
+    let stopwords: Vec<String> = stopwords
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect();
+    let words: Vec<(Range<usize>, String)> = spelling::words(text)
+        .map(|range| {
+            let word = text[range.clone()].to_lowercase();
+            (range, word)
+        })
+        .filter(|(_, word)| !stopwords.contains(word))
+        .collect();
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (_, word) in &words {
+        *counts.entry(word).or_default() += 1;
+    }
+    words
+        .iter()
+        .filter(|(_, word)| counts[word.as_str()] > threshold)
+        .map(|(range, _)| range.clone())
+        .collect()
+}
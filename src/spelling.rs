@@ -0,0 +1,163 @@
+//! Spell checking for the content editor against a Hunspell dictionary, plus a personal word
+//! list kept next to the posts file.
+
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use spellbook::Dictionary;
+
+pub const DEFAULT_LANGUAGE: &str = "en_US";
+const PERSONAL_DICTIONARY_FILE_NAME: &str = "dictionary.txt";
+/// Where Hunspell dictionaries (`en_US.aff` + `en_US.dic`) are usually installed.
+const SYSTEM_DICTIONARY_DIRS: [&str; 4] = [
+    "/usr/share/hunspell",
+    "/usr/share/myspell",
+    "/usr/share/myspell/dicts",
+    "/Library/Spelling",
+];
+/// The same, relative to the home directory.
+const USER_DICTIONARY_DIRS: [&str; 2] = [".local/share/hunspell", "Library/Spelling"];
+
+pub struct SpellChecker {
+    language: String,
+    dictionary: Dictionary,
+    /// The posts file the personal words were loaded from and are saved beside.
+    posts_file: Option<PathBuf>,
+    /// The text `misspelled` was found in, so it's only checked again once it changes.
+    checked: String,
+    misspelled: Vec<Range<usize>>,
+}
+
+impl SpellChecker {
+    /// Load the dictionary for `language`, e.g. `en_US`, and the personal words beside
+    /// `posts_file`.
+    pub fn load(language: &str, posts_file: Option<&Path>) -> Result<SpellChecker, String> {
+        let dir = dictionary_dirs()
+            .into_iter()
+            .find(|dir| dir.join(format!("{}.dic", language)).exists())
+            .ok_or_else(|| format!("No {} spelling dictionary is installed", language))?;
+        let read = |extension: &str| {
+            let path = dir.join(format!("{}.{}", language, extension));
+            std::fs::read_to_string(&path)
+                .map_err(|e| format!("Could not read {}: {}", path.display(), e))
+        };
+        let mut dictionary = Dictionary::new(&read("aff")?, &read("dic")?)
+            .map_err(|e| format!("Could not load the {} dictionary: {}", language, e))?;
+        if let Some(posts_file) = posts_file
+            && let Ok(words) = std::fs::read_to_string(personal_dictionary_file(posts_file))
+        {
+            for word in words.lines().filter(|word| !word.is_empty()) {
+                if let Err(e) = dictionary.add(word) {
+                    log::warn!("Skipping personal dictionary word {:?}: {}", word, e);
+                }
+            }
+        }
+        Ok(SpellChecker {
+            language: language.to_owned(),
+            dictionary,
+            posts_file: posts_file.map(Path::to_path_buf),
+            checked: String::new(),
+            misspelled: vec![],
+        })
+    }
+
+    /// Whether this was loaded for `language` and the personal words of `posts_file`.
+    pub fn is_for(&self, language: &str, posts_file: Option<&Path>) -> bool {
+        self.language == language && self.posts_file.as_deref() == posts_file
+    }
+
+    /// The byte ranges of the misspelled words in `text`.
+    pub fn misspelled(&mut self, text: &str) -> &[Range<usize>] {
+        if text != self.checked {
+            self.misspelled = words(text)
+                .filter(|word| !self.dictionary.check(&text[word.clone()]))
+                .collect();
+            self.checked = text.to_owned();
+        }
+        &self.misspelled
+    }
+
+    pub fn suggestions(&self, word: &str) -> Vec<String> {
+        let mut suggestions = vec![];
+        self.dictionary.suggest(word, &mut suggestions);
+        suggestions
+    }
+
+    /// Accept `word` from now on, remembering it in the personal word list if there is a
+    /// posts file to keep it beside.
+    pub fn add_word(&mut self, word: &str) -> std::io::Result<()> {
+        self.dictionary
+            .add(word)
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        // So the word stops being underlined straight away:
+        self.checked.clear();
+        let Some(posts_file) = &self.posts_file else {
+            return Ok(());
+        };
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(personal_dictionary_file(posts_file))?;
+        writeln!(file, "{}", word)
+    }
+}
+
+/// The languages there is a dictionary installed for, e.g. `en_US`.
+pub fn available_languages() -> Vec<String> {
+    let mut languages: Vec<String> = dictionary_dirs()
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "dic"))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+        .collect();
+    languages.sort();
+    languages.dedup();
+    languages
+}
+
+/// The byte range of the word around `index` in `text`, if there is one.
+pub fn word_at(text: &str, index: usize) -> Option<Range<usize>> {
+    words(text).find(|word| word.start <= index && index <= word.end)
+}
+
+fn dictionary_dirs() -> Vec<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let user_dirs = home
+        .iter()
+        .flat_map(|home| USER_DICTIONARY_DIRS.iter().map(|dir| home.join(dir)));
+    user_dirs
+        .chain(SYSTEM_DICTIONARY_DIRS.iter().map(PathBuf::from))
+        .collect()
+}
+
+fn personal_dictionary_file(posts_file: &Path) -> PathBuf {
+    posts_file.with_file_name(PERSONAL_DICTIONARY_FILE_NAME)
+}
+
+/// The byte ranges of the words in `text`: runs of letters, with apostrophes allowed
+/// between them (`don't`). Words with digits in them are left out.
+pub fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'' || c == '’';
+    let mut rest = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        loop {
+            let (start, _) = rest.find(|(_, c)| c.is_alphanumeric())?;
+            let mut end = text.len();
+            while let Some(&(index, c)) = rest.peek() {
+                if !is_word_char(c) {
+                    end = index;
+                    break;
+                }
+                rest.next();
+            }
+            let word = text[start..end].trim_end_matches(['\'', '’']);
+            if !word.chars().any(|c| c.is_numeric()) {
+                return Some(start..start + word.len());
+            }
+        }
+    })
+}
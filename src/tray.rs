@@ -0,0 +1,86 @@
+//! The system-tray icon the window hides into when "Minimize to tray" is on, with a menu to
+//! show the window again or quit.
+
+use egui::ViewportCommand;
+use tray_icon::menu::{Menu, MenuEvent, MenuItem};
+use tray_icon::{Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent};
+
+use crate::i18n;
+
+const SHOW_WINDOW_ID: &str = "show_window";
+const QUIT_ID: &str = "quit";
+
+/// The icon stays in the tray for as long as this is kept.
+pub struct Tray {
+    _icon: TrayIcon,
+}
+
+impl Tray {
+    pub fn new(ctx: &egui::Context, language: i18n::Language) -> Result<Tray, String> {
+        let menu = Menu::new();
+        menu.append_items(&[
+            &MenuItem::with_id(
+                SHOW_WINDOW_ID,
+                i18n::tr(language, "show_window"),
+                true,
+                None,
+            ),
+            &MenuItem::with_id(QUIT_ID, i18n::tr(language, "quit"), true, None),
+        ])
+        .map_err(|e| e.to_string())?;
+        let image = image::load_from_memory(include_bytes!("../assets/woa_logo_only.png"))
+            .map_err(|e| e.to_string())?
+            .to_rgba8();
+        let (width, height) = image.dimensions();
+        let icon = Icon::from_rgba(image.into_raw(), width, height).map_err(|e| e.to_string())?;
+        install_event_handlers(ctx);
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip(i18n::tr(language, "app_title"))
+            .with_icon(icon)
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Tray { _icon: icon })
+    }
+}
+
+/// Hide the window, leaving only the tray icon.
+pub fn hide_window(ctx: &egui::Context) {
+    ctx.send_viewport_cmd(ViewportCommand::Visible(false));
+}
+
+fn show_window(ctx: &egui::Context) {
+    ctx.send_viewport_cmd(ViewportCommand::Visible(true));
+    ctx.send_viewport_cmd(ViewportCommand::Focus);
+    // The window doesn't redraw while it's hidden, so nothing else would run the commands:
+    ctx.request_repaint();
+}
+
+/// The tray's events arrive on another thread, and their handlers can only be set once, so
+/// they're set the first time a tray is made and act on the window straight away.
+fn install_event_handlers(ctx: &egui::Context) {
+    static INSTALLED: std::sync::Once = std::sync::Once::new();
+    INSTALLED.call_once(|| {
+        let menu_ctx = ctx.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if event.id == SHOW_WINDOW_ID {
+                show_window(&menu_ctx);
+            } else if event.id == QUIT_ID {
+                // Shown first, so it can ask about unsaved changes:
+                show_window(&menu_ctx);
+                menu_ctx.send_viewport_cmd(ViewportCommand::Close);
+            }
+        }));
+        let icon_ctx = ctx.clone();
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_window(&icon_ctx);
+            }
+        }));
+    });
+}
@@ -0,0 +1,61 @@
+use azifirsteframe::blog::{self, LoggedAction};
+
+#[test]
+fn appends_a_line_per_action() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let published = LoggedAction {
+        action: "publish",
+        title: "First",
+        error: None,
+    };
+    let failed = LoggedAction {
+        action: "delete",
+        title: "Second",
+        error: Some("No post found at index 3".to_string()),
+    };
+    blog::log_action(&posts_file, &published).unwrap();
+    blog::log_action(&posts_file, &failed).unwrap();
+
+    assert_eq!(blog::log_file(&posts_file), dir.path().join("blog.log"));
+    let lines = blog::log_tail(&posts_file, 10).unwrap();
+    assert_eq!(lines.len(), 2);
+    let fields: Vec<&str> = lines[0].split('\t').collect();
+    assert_eq!(&fields[1..], ["publish", "First", "ok"]);
+    assert!(chrono::DateTime::parse_from_rfc3339(fields[0]).is_ok());
+    assert!(lines[1].ends_with("delete\tSecond\tfailed: No post found at index 3"));
+}
+
+#[test]
+fn keeps_each_action_on_one_line() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let action = LoggedAction {
+        action: "publish",
+        title: "Tab\there",
+        error: Some("line\nbreak".to_string()),
+    };
+    blog::log_action(&posts_file, &action).unwrap();
+    let lines = blog::log_tail(&posts_file, 10).unwrap();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].split('\t').count(), 4);
+}
+
+#[test]
+fn tails_the_last_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    assert!(blog::log_tail(&posts_file, 5).unwrap().is_empty());
+    for title in ["a", "b", "c"] {
+        let action = LoggedAction {
+            action: "update",
+            title,
+            error: None,
+        };
+        blog::log_action(&posts_file, &action).unwrap();
+    }
+    let lines = blog::log_tail(&posts_file, 2).unwrap();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("\tb\t"));
+    assert!(lines[1].contains("\tc\t"));
+}
@@ -0,0 +1,52 @@
+use azifirsteframe::blog::{self, BlogPostsForJson};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+/// The text of every `<id>` inside an `<entry>` of the feed at `xml`.
+fn entry_ids(xml: &str) -> Vec<String> {
+    let mut reader = Reader::from_str(xml);
+    let (mut in_entry, mut in_id) = (false, false);
+    let mut ids = vec![];
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(tag) => match tag.name().as_ref() {
+                "entry" => in_entry = true,
+                "id" => in_id = in_entry,
+                _ => {}
+            },
+            Event::End(tag) => match tag.name().as_ref() {
+                "entry" => in_entry = false,
+                "id" => in_id = false,
+                _ => {}
+            },
+            Event::Text(text) if in_id => ids.push(text.to_string()),
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    ids
+}
+
+#[test]
+fn entries_sharing_a_slug_get_different_ids() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    // As when posts are brought in from another blog that used the same slugs:
+    std::fs::write(
+        &posts_file,
+        r#"{"schema_version": 1, "page": {"title": "Blog", "css": ""}, "footer_links": [],
+            "posts": [{"woa_time": "2024:01:02:00:00:00", "title": "Same", "content": ["b"],
+                       "slug": "same"},
+                      {"woa_time": "2024:01:01:00:00:00", "title": "Same", "content": ["a"],
+                       "slug": "same"}]}"#,
+    )
+    .unwrap();
+    let blog_posts = BlogPostsForJson::from_file(&posts_file).unwrap();
+    let atom = dir.path().join("atom.xml");
+    blog::export_atom(&atom, &blog_posts, false).unwrap();
+
+    let ids = entry_ids(&std::fs::read_to_string(&atom).unwrap());
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+    assert!(ids.iter().all(|id| id.starts_with("urn:woa:post:same:")));
+}
@@ -0,0 +1,40 @@
+use azifirsteframe::blog::autolink;
+
+#[test]
+fn every_url_on_a_line_is_linked() {
+    assert_eq!(
+        autolink("see https://a.example and http://b.example/path?q=1"),
+        "see [https://a.example](https://a.example) and \
+         [http://b.example/path?q=1](http://b.example/path?q=1)"
+    );
+}
+
+#[test]
+fn trailing_punctuation_stays_outside_the_link() {
+    assert_eq!(
+        autolink("Go to https://a.example/x. Or https://b.example, then (https://c.example)!"),
+        "Go to [https://a.example/x](https://a.example/x). Or \
+         [https://b.example](https://b.example), then \
+         ([https://c.example](https://c.example))!"
+    );
+}
+
+#[test]
+fn linked_urls_are_left_alone() {
+    for line in [
+        "[the site](https://a.example)",
+        "[https://a.example](https://a.example)",
+        "<https://a.example>",
+        "`curl https://a.example`",
+    ] {
+        assert_eq!(autolink(line), line);
+    }
+}
+
+#[test]
+fn lines_without_urls_are_unchanged() {
+    assert_eq!(
+        autolink("nothing to see here: http://"),
+        "nothing to see here: http://"
+    );
+}
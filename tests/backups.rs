@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+
+use azifirsteframe::blog::{self, BlogPostsForJson, Page, Post};
+
+fn backup(path: &Path, number: usize) -> PathBuf {
+    PathBuf::from(format!("{}.{}", path.display(), number))
+}
+
+fn post(title: &str) -> Post {
+    Post {
+        title: title.to_string(),
+        content: vec!["content".to_string()],
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    }
+}
+
+#[test]
+fn keeps_only_the_newest_backups() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    for save in 1..=6 {
+        std::fs::write(&path, format!("save {}", save)).unwrap();
+        blog::rotate_backups(&path, 3).unwrap();
+    }
+    assert_eq!(std::fs::read_to_string(backup(&path, 1)).unwrap(), "save 6");
+    assert_eq!(std::fs::read_to_string(backup(&path, 2)).unwrap(), "save 5");
+    assert_eq!(std::fs::read_to_string(backup(&path, 3)).unwrap(), "save 4");
+    assert!(!backup(&path, 4).exists());
+}
+
+#[test]
+fn prunes_backups_past_a_lowered_count() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    std::fs::write(&path, "posts").unwrap();
+    for _ in 0..4 {
+        blog::rotate_backups(&path, 4).unwrap();
+    }
+    blog::rotate_backups(&path, 2).unwrap();
+    assert!(backup(&path, 2).exists());
+    assert!(!backup(&path, 3).exists());
+    assert!(!backup(&path, 4).exists());
+
+    blog::rotate_backups(&path, 0).unwrap();
+    assert!(!backup(&path, 1).exists());
+}
+
+#[test]
+fn prunes_backups_past_a_gap_in_the_numbers() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    std::fs::write(&path, "posts").unwrap();
+    for number in 1..=10 {
+        std::fs::write(backup(&path, number), format!("backup {}", number)).unwrap();
+    }
+    std::fs::remove_file(backup(&path, 2)).unwrap();
+
+    blog::rotate_backups(&path, 3).unwrap();
+    assert_eq!(std::fs::read_to_string(backup(&path, 1)).unwrap(), "posts");
+    assert_eq!(
+        std::fs::read_to_string(backup(&path, 2)).unwrap(),
+        "backup 1"
+    );
+    assert_eq!(
+        std::fs::read_to_string(backup(&path, 3)).unwrap(),
+        "backup 3"
+    );
+    for number in 4..=10 {
+        assert!(!backup(&path, number).exists());
+    }
+}
+
+#[test]
+fn prunes_backups_past_a_lowered_count_with_a_gap() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    std::fs::write(&path, "posts").unwrap();
+    for _ in 0..10 {
+        blog::rotate_backups(&path, 10).unwrap();
+    }
+    std::fs::remove_file(backup(&path, 2)).unwrap();
+
+    blog::rotate_backups(&path, 1).unwrap();
+    assert!(backup(&path, 1).exists());
+    for number in 2..=10 {
+        assert!(!backup(&path, number).exists());
+    }
+}
+
+#[test]
+fn does_nothing_before_the_first_save() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    blog::rotate_backups(&path, 5).unwrap();
+    assert!(!backup(&path, 1).exists());
+}
+
+#[test]
+fn saving_keeps_the_pages_number_of_backups() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    let page = Page {
+        max_backups: 2,
+        ..blog_posts.page().clone()
+    };
+    blog::save_settings(page, vec![], &mut blog_posts, &path).unwrap();
+    for title in ["a", "b", "c", "d"] {
+        blog::publish(post(title), &mut blog_posts, &path).unwrap();
+    }
+    let newest_backup = BlogPostsForJson::from_file(&backup(&path, 1)).unwrap();
+    assert_eq!(newest_backup.posts().len(), 3);
+    assert!(backup(&path, 2).exists());
+    assert!(!backup(&path, 3).exists());
+}
+
+#[test]
+fn the_newest_backup_holds_the_file_from_before_the_save() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    blog::publish(post("first"), &mut blog_posts, &path).unwrap();
+    let before = std::fs::read_to_string(&path).unwrap();
+
+    blog::publish(post("second"), &mut blog_posts, &path).unwrap();
+    assert_eq!(std::fs::read_to_string(backup(&path, 1)).unwrap(), before);
+    assert_ne!(std::fs::read_to_string(&path).unwrap(), before);
+}
@@ -0,0 +1,24 @@
+use azifirsteframe::blog::content_stats;
+
+#[test]
+fn empty_text_has_nothing_to_read() {
+    assert_eq!(content_stats(""), (0, 0, 0));
+}
+
+#[test]
+fn runs_of_spaces_and_line_breaks_dont_add_words() {
+    let (words, characters, _) = content_stats("  one   two\n\n three ");
+    assert_eq!(words, 3);
+    assert_eq!(characters, 20);
+}
+
+#[test]
+fn counts_unicode_words_and_characters() {
+    assert_eq!(content_stats("café naïve 日本語"), (3, 14, 1));
+}
+
+#[test]
+fn reading_time_rounds_up() {
+    assert_eq!(content_stats(&"word ".repeat(200)).2, 1);
+    assert_eq!(content_stats(&"word ".repeat(201)).2, 2);
+}
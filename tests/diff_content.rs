@@ -0,0 +1,42 @@
+use azifirsteframe::blog::{self, LineChange};
+
+fn lines(text: &str) -> Vec<String> {
+    blog::split_on_line_breaks(text)
+}
+
+#[test]
+fn unchanged_content_has_no_changes() {
+    let changes = blog::diff_content(&lines("one\ntwo"), "one\ntwo");
+    assert_eq!(
+        changes,
+        vec![
+            LineChange::Unchanged("one".to_string()),
+            LineChange::Unchanged("two".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn marks_added_and_removed_lines_in_place() {
+    let changes = blog::diff_content(&lines("one\ntwo\nthree"), "one\n2\nthree\nfour");
+    assert_eq!(
+        changes,
+        vec![
+            LineChange::Unchanged("one".to_string()),
+            LineChange::Removed("two".to_string()),
+            LineChange::Added("2".to_string()),
+            LineChange::Unchanged("three".to_string()),
+            LineChange::Added("four".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn ignores_windows_line_breaks() {
+    let changes = blog::diff_content(&lines("one\ntwo"), "one\r\ntwo");
+    assert!(
+        changes
+            .iter()
+            .all(|change| matches!(change, LineChange::Unchanged(_)))
+    );
+}
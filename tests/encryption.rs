@@ -0,0 +1,60 @@
+use azifirsteframe::blog::{self, BlogError, BlogPostsForJson, Post};
+
+#[test]
+fn encrypted_file_needs_the_right_passphrase() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    let mut blog_posts = blog::load(&filename).unwrap();
+    let post = Post {
+        title: "Secret".to_string(),
+        content: vec!["hidden".to_string()],
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    };
+    blog::publish(post, &mut blog_posts, &filename).unwrap();
+    blog::set_passphrase(Some("hunter2".to_string()), &mut blog_posts, &filename).unwrap();
+
+    let on_disk = std::fs::read(&filename).unwrap();
+    assert!(!String::from_utf8_lossy(&on_disk).contains("Secret"));
+    assert!(matches!(
+        BlogPostsForJson::from_file(&filename),
+        Err(BlogError::PassphraseRequired)
+    ));
+    assert!(matches!(
+        blog::load_encrypted(&filename, "wrong"),
+        Err(BlogError::DecryptionFailed)
+    ));
+    let mut decrypted = blog::load_encrypted(&filename, "hunter2").unwrap();
+    assert_eq!(decrypted.posts()[0].title, "Secret");
+
+    blog::set_passphrase(None, &mut decrypted, &filename).unwrap();
+    assert_eq!(
+        BlogPostsForJson::from_file(&filename).unwrap().posts()[0].title,
+        "Secret"
+    );
+}
+
+#[test]
+fn dry_run_works_on_an_encrypted_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    let mut blog_posts = blog::load(&filename).unwrap();
+    blog::set_passphrase(Some("hunter2".to_string()), &mut blog_posts, &filename).unwrap();
+    let blog_posts = blog::load_encrypted(&filename, "hunter2").unwrap();
+    let before = std::fs::read(&filename).unwrap();
+    let post = Post {
+        title: "Secret".to_string(),
+        content: vec!["hidden".to_string()],
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    };
+
+    let plan = blog::publish_dry_run(&post, &blog_posts, &filename).unwrap();
+
+    assert!(!plan.creates_file);
+    assert_eq!(plan.slug, "secret");
+    assert_eq!(plan.post_count, 1);
+    assert_eq!(std::fs::read(&filename).unwrap(), before);
+}
@@ -0,0 +1,34 @@
+use azifirsteframe::blog::PostForJson;
+
+fn post_with_content(content: &[&str]) -> PostForJson {
+    PostForJson {
+        woa_time: "".to_string(),
+        title: "Title".to_string(),
+        content: content.iter().map(|line| line.to_string()).collect(),
+        tags: vec![],
+        slug: "title".to_string(),
+        published: true,
+        assets: vec![],
+        history: vec![],
+    }
+}
+
+#[test]
+fn joins_lines_with_spaces() {
+    let post = post_with_content(&["First line", "", "second line"]);
+    assert_eq!(post.excerpt(80), "First line second line");
+}
+
+#[test]
+fn truncates_with_an_ellipsis() {
+    let post = post_with_content(&["The quick brown fox"]);
+    assert_eq!(post.excerpt(9), "The quick…");
+}
+
+#[test]
+fn truncates_on_char_boundaries() {
+    let post = post_with_content(&["Café crème 🎉🎉 déjà vu"]);
+    assert_eq!(post.excerpt(4), "Café…");
+    assert_eq!(post.excerpt(12), "Café crème 🎉…");
+    assert_eq!(post.excerpt(100), "Café crème 🎉🎉 déjà vu");
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use azifirsteframe::blog::{self, BlogPostsForJson, ExportManifest, Post};
+
+fn post(title: &str, content: &str) -> Post {
+    Post {
+        title: title.to_string(),
+        content: blog::split_on_line_breaks(content),
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    }
+}
+
+/// Every file under `dir`, relative to it with `/` separators.
+fn files_in(dir: &std::path::Path) -> BTreeSet<String> {
+    let mut files = BTreeSet::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(current) = dirs.pop() {
+        for entry in std::fs::read_dir(&current).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                let relative = path.strip_prefix(dir).unwrap();
+                let parts: Vec<_> = relative.iter().map(|p| p.to_string_lossy()).collect();
+                files.insert(parts.join("/"));
+            }
+        }
+    }
+    files
+}
+
+#[test]
+fn manifest_lists_the_files_written() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    std::fs::create_dir(dir.path().join("images")).unwrap();
+    std::fs::write(dir.path().join("images/cat.png"), b"png").unwrap();
+    let mut blog_posts = BlogPostsForJson::default();
+    blog::publish(post("First", "one"), &mut blog_posts, &filename).unwrap();
+    blog::publish(
+        post("Cat", "![](images/cat.png)"),
+        &mut blog_posts,
+        &filename,
+    )
+    .unwrap();
+    let output_dir = dir.path().join("site");
+
+    let manifest = blog::export_html(&output_dir, &blog_posts, &filename, false).unwrap();
+
+    let mut listed: BTreeSet<String> = manifest.files.iter().map(|f| f.path.clone()).collect();
+    listed.insert("manifest.json".to_string());
+    assert_eq!(listed, files_in(&output_dir));
+    for post in blog_posts.posts() {
+        let entry = manifest
+            .files
+            .iter()
+            .find(|file| file.slug.as_ref() == Some(&post.slug))
+            .unwrap();
+        assert_eq!(entry.woa_time.as_ref(), Some(&post.woa_time));
+    }
+    let asset = manifest.files.iter().find(|f| f.path == "images/cat.png");
+    assert_eq!(asset.unwrap().slug, None);
+}
+
+#[test]
+fn manifest_file_matches_the_returned_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    blog::publish(post("Only", "post"), &mut blog_posts, &filename).unwrap();
+    let output_dir = dir.path().join("site");
+
+    let manifest = blog::export_html(&output_dir, &blog_posts, &filename, false).unwrap();
+
+    let saved = std::fs::read_to_string(output_dir.join("manifest.json")).unwrap();
+    let saved: ExportManifest = serde_json::from_str(&saved).unwrap();
+    assert_eq!(saved, manifest);
+}
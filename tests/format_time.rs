@@ -0,0 +1,24 @@
+use azifirsteframe::blog::{format_time, parse_time};
+use chrono::{Local, TimeZone};
+
+#[test]
+fn formatted_times_parse_back_to_the_same_time() {
+    let time = Local.with_ymd_and_hms(2024, 2, 29, 23, 59, 58).unwrap();
+    for format in [
+        "%Y:%m:%d:%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+        "%d/%m/%Y %H:%M:%S",
+    ] {
+        let formatted = format_time(time, format);
+        assert_eq!(parse_time(&formatted, format).unwrap(), time, "{}", format);
+    }
+}
+
+#[test]
+fn the_default_format_writes_soleilfou_times() {
+    let time = Local.with_ymd_and_hms(2024, 1, 5, 8, 3, 0).unwrap();
+    assert_eq!(
+        format_time(time, "%Y:%m:%d:%H:%M:%S"),
+        "2024:01:05:08:03:00"
+    );
+}
@@ -0,0 +1,81 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+
+use azifirsteframe::blog::{self, BlogError, Page, Post};
+
+fn post() -> Post {
+    Post {
+        title: "Hello".to_string(),
+        content: vec!["Over the wire".to_string()],
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    }
+}
+
+/// Answer one request with `status` and `body`, handing back the request body it got.
+fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/posts", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+        let mut request_body = vec![0; content_length];
+        reader.read_exact(&mut request_body).unwrap();
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        String::from_utf8(request_body).unwrap()
+    });
+    (url, server)
+}
+
+#[test]
+fn posts_the_post_as_json() {
+    let (url, server) = serve_once("201 Created", "");
+    blog::publish_to_url(post(), &Page::default(), &url).unwrap();
+    let sent: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+    assert_eq!(sent["title"], "Hello");
+    assert_eq!(sent["slug"], "hello");
+    assert_eq!(sent["content"][0], "Over the wire");
+}
+
+#[test]
+fn other_statuses_are_http_errors() {
+    let (url, server) = serve_once("409 Conflict", "slug taken");
+    let result = blog::publish_to_url(post(), &Page::default(), &url);
+    server.join().unwrap();
+    match result {
+        Err(BlogError::HttpError { status, body }) => {
+            assert_eq!(status, 409);
+            assert_eq!(body, "slug taken");
+        }
+        other => panic!("expected an HttpError, got {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_posts_are_not_sent() {
+    let mut post = post();
+    post.title.clear();
+    let result = blog::publish_to_url(post, &Page::default(), "http://127.0.0.1:9/posts");
+    assert!(matches!(result, Err(BlogError::EmptyTitle)));
+}
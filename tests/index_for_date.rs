@@ -0,0 +1,61 @@
+use azifirsteframe::blog::{BlogPostsForJson, Post};
+use chrono::{Local, NaiveDate, TimeZone};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// Posts at noon on each of `dates`, which end up newest first.
+fn blog_on(dates: &[NaiveDate]) -> BlogPostsForJson {
+    let mut blog_posts = BlogPostsForJson::default();
+    for day in dates {
+        let noon = day.and_hms_opt(12, 0, 0).unwrap();
+        blog_posts.add_post(Post {
+            title: day.to_string(),
+            content: vec!["content".to_string()],
+            tags: vec![],
+            published: true,
+            scheduled_time: Some(Local.from_local_datetime(&noon).unwrap()),
+        });
+    }
+    blog_posts
+}
+
+fn title_for(blog_posts: &BlogPostsForJson, day: NaiveDate) -> Option<String> {
+    let index = blog_posts.index_for_date(day)?;
+    Some(blog_posts.posts()[index].title.clone())
+}
+
+#[test]
+fn finds_the_post_on_that_day() {
+    let blog_posts = blog_on(&[date(2024, 1, 1), date(2024, 2, 1), date(2024, 3, 1)]);
+    assert_eq!(
+        title_for(&blog_posts, date(2024, 2, 1)).unwrap(),
+        "2024-02-01"
+    );
+}
+
+#[test]
+fn falls_back_to_the_nearest_earlier_post() {
+    let blog_posts = blog_on(&[date(2024, 1, 1), date(2024, 2, 1), date(2024, 3, 1)]);
+    assert_eq!(
+        title_for(&blog_posts, date(2024, 2, 28)).unwrap(),
+        "2024-02-01"
+    );
+}
+
+#[test]
+fn dates_after_the_newest_post_give_the_first() {
+    let blog_posts = blog_on(&[date(2024, 1, 1), date(2024, 3, 1)]);
+    assert_eq!(blog_posts.index_for_date(date(2025, 1, 1)), Some(0));
+}
+
+#[test]
+fn dates_before_the_oldest_post_give_none() {
+    let blog_posts = blog_on(&[date(2024, 1, 1), date(2024, 3, 1)]);
+    assert_eq!(blog_posts.index_for_date(date(2023, 12, 31)), None);
+    assert_eq!(
+        BlogPostsForJson::default().index_for_date(date(2024, 1, 1)),
+        None
+    );
+}
@@ -0,0 +1,29 @@
+use azifirsteframe::blog;
+
+const V0_POSTS: &str = include_str!("fixtures/v0_posts.json");
+
+#[test]
+fn an_unversioned_file_is_saved_as_the_current_version_with_its_posts() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    std::fs::write(&posts_file, V0_POSTS).unwrap();
+
+    let mut blog_posts = blog::load(&posts_file).unwrap();
+    blog::save(&mut blog_posts, &posts_file).unwrap();
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&posts_file).unwrap()).unwrap();
+    assert_eq!(saved["schema_version"], 1);
+    assert_eq!(saved["page"]["title"], "Old Blog");
+    assert_eq!(saved["footer_links"][0]["url"], "https://example.com");
+    let posts = saved["posts"].as_array().unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0]["title"], "Second Post");
+    assert_eq!(posts[0]["woa_time"], "2023:05:02:09:30:00");
+    assert_eq!(posts[0]["slug"], "second-post");
+    assert_eq!(
+        posts[1]["content"],
+        serde_json::json!(["Hello,", "", "world."])
+    );
+    assert_eq!(posts[1]["slug"], "first-post");
+}
@@ -0,0 +1,50 @@
+use azifirsteframe::blog::{self, PostForJson};
+
+#[test]
+fn stray_carriage_returns_are_removed_on_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    std::fs::write(
+        &posts_file,
+        r#"{"schema_version": 1, "page": {"title": "Blog", "css": ""}, "footer_links": [],
+            "posts": [{"woa_time": "2024:01:01:00:00:00", "title": "Windows\r",
+                       "content": ["first\r", "\r", "second\r\nthird"], "slug": "windows"}]}"#,
+    )
+    .unwrap();
+    let blog_posts = blog::load(&posts_file).unwrap();
+    let post = &blog_posts.posts()[0];
+    assert_eq!(post.title, "Windows");
+    assert_eq!(post.content, vec!["first", "", "second", "third"]);
+}
+
+#[test]
+fn clean_content_is_left_alone() {
+    let mut post = PostForJson {
+        woa_time: "".to_string(),
+        title: "Title".to_string(),
+        content: vec!["a".to_string(), "".to_string(), "b".to_string()],
+        tags: vec![],
+        slug: "title".to_string(),
+        published: true,
+        assets: vec![],
+        history: vec![],
+    };
+    post.normalize();
+    assert_eq!(post.content, vec!["a", "", "b"]);
+}
+
+#[test]
+fn a_title_only_loses_the_whitespace_around_it() {
+    let mut post = PostForJson {
+        woa_time: "".to_string(),
+        title: " Two\r\nlines\r".to_string(),
+        content: vec![],
+        tags: vec![],
+        slug: "two-lines".to_string(),
+        published: true,
+        assets: vec![],
+        history: vec![],
+    };
+    post.normalize();
+    assert_eq!(post.title, "Two\r\nlines");
+}
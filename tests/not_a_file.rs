@@ -0,0 +1,42 @@
+use azifirsteframe::blog::{self, BlogError, BlogPostsForJson};
+
+#[test]
+fn reading_a_folder_says_so() {
+    let dir = tempfile::tempdir().unwrap();
+    let result = BlogPostsForJson::from_file(dir.path());
+    assert!(matches!(result, Err(BlogError::NotAFile(_))));
+    assert!(matches!(
+        blog::load(dir.path()),
+        Err(BlogError::NotAFile(_))
+    ));
+}
+
+#[test]
+fn saving_over_a_folder_leaves_it_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    let folder = dir.path().join("posts.json");
+    std::fs::create_dir(&folder).unwrap();
+    let result = BlogPostsForJson::default().save_to_file(&folder);
+    assert!(matches!(result, Err(BlogError::NotAFile(_))));
+    assert!(folder.is_dir());
+}
+
+#[test]
+fn a_missing_file_is_still_fine_to_save() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    BlogPostsForJson::default().save_to_file(&filename).unwrap();
+    assert!(BlogPostsForJson::from_file(&filename).is_ok());
+}
+
+#[cfg(unix)]
+#[test]
+fn reading_a_symlink_loop_says_so() {
+    let dir = tempfile::tempdir().unwrap();
+    let a = dir.path().join("a.json");
+    let b = dir.path().join("b.json");
+    std::os::unix::fs::symlink(&b, &a).unwrap();
+    std::os::unix::fs::symlink(&a, &b).unwrap();
+    let result = BlogPostsForJson::from_file(&a);
+    assert!(matches!(result, Err(BlogError::NotAFile(_))));
+}
@@ -0,0 +1,55 @@
+use azifirsteframe::blog::{self, BlogPostsForJson, Post, wrap_line};
+
+#[test]
+fn wraps_greedily_between_words() {
+    assert_eq!(
+        wrap_line("the quick brown fox jumps over", 10),
+        vec!["the quick", "brown fox", "jumps over"]
+    );
+}
+
+#[test]
+fn words_longer_than_the_width_get_their_own_line() {
+    assert_eq!(
+        wrap_line("see https://example.com/a/long/path now", 10),
+        vec!["see", "https://example.com/a/long/path", "now"]
+    );
+}
+
+#[test]
+fn empty_lines_stay_empty() {
+    assert_eq!(wrap_line("", 10), vec![""]);
+    assert_eq!(wrap_line("   ", 10), vec![""]);
+}
+
+#[test]
+fn a_line_that_fits_is_unchanged() {
+    assert_eq!(wrap_line("exactly ten", 11), vec!["exactly ten"]);
+}
+
+#[test]
+fn exports_titles_underlined_and_content_wrapped() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let text_file = dir.path().join("posts.txt");
+    let mut blog_posts = BlogPostsForJson::default();
+    let post = Post {
+        title: "Hello".to_string(),
+        content: vec![
+            "one two three".to_string(),
+            "".to_string(),
+            "four".to_string(),
+        ],
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    };
+    blog::publish(post, &mut blog_posts, &posts_file).unwrap();
+    blog::export_text(&text_file, &blog_posts, 8, false).unwrap();
+    let text = std::fs::read_to_string(text_file).unwrap();
+    let woa_time = &blog_posts.posts()[0].woa_time;
+    assert_eq!(
+        text,
+        format!("{}\nHello\n=====\n\none two\nthree\n\nfour\n", woa_time)
+    );
+}
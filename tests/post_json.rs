@@ -0,0 +1,70 @@
+use azifirsteframe::blog::{self, BlogPostsForJson, Post, PostForJson};
+
+fn post(title: &str) -> Post {
+    Post {
+        title: title.to_string(),
+        content: vec![
+            "line one".to_string(),
+            "".to_string(),
+            "line two".to_string(),
+        ],
+        tags: vec!["moving".to_string()],
+        published: true,
+        scheduled_time: None,
+    }
+}
+
+#[test]
+fn a_post_round_trips_through_json() {
+    let mut blog_posts = BlogPostsForJson::default();
+    blog_posts.add_post(post("Hello"));
+    let original = &blog_posts.posts()[0];
+    let copy = PostForJson::from_json_string(&original.to_json_string().unwrap()).unwrap();
+    assert_eq!(copy.title, original.title);
+    assert_eq!(copy.content, original.content);
+    assert_eq!(copy.tags, original.tags);
+    assert_eq!(copy.slug, original.slug);
+    assert_eq!(copy.woa_time, original.woa_time);
+}
+
+#[test]
+fn imported_posts_get_a_free_slug() {
+    let dir = tempfile::tempdir().unwrap();
+    let source_file = dir.path().join("source.json");
+    let target_file = dir.path().join("target.json");
+    let post_file = dir.path().join("hello.json");
+
+    let mut source = BlogPostsForJson::default();
+    blog::publish(post("Hello"), &mut source, &source_file).unwrap();
+    blog::export_post(&post_file, &source.posts()[0]).unwrap();
+
+    let mut target = BlogPostsForJson::default();
+    blog::publish(post("Hello"), &mut target, &target_file).unwrap();
+    assert_eq!(
+        blog::import_post(&post_file, &mut target, &target_file).unwrap(),
+        "hello-2"
+    );
+    assert_eq!(
+        blog::import_post(&post_file, &mut target, &target_file).unwrap(),
+        "hello-3"
+    );
+
+    let saved = blog::load(&target_file).unwrap();
+    let mut slugs: Vec<&str> = saved
+        .posts()
+        .iter()
+        .map(|post| post.slug.as_str())
+        .collect();
+    slugs.sort();
+    assert_eq!(slugs, vec!["hello", "hello-2", "hello-3"]);
+}
+
+#[test]
+fn importing_something_else_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let not_a_post = dir.path().join("not_a_post.json");
+    std::fs::write(&not_a_post, "[1, 2, 3]").unwrap();
+    let mut blog_posts = BlogPostsForJson::default();
+    let result = blog::import_post(&not_a_post, &mut blog_posts, &dir.path().join("posts.json"));
+    assert!(matches!(result, Err(blog::BlogError::PostFileUnParsable)));
+}
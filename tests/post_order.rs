@@ -0,0 +1,71 @@
+use azifirsteframe::blog::{self, BlogPostsForJson, Post, PostOrder};
+use chrono::{Duration, Local};
+
+fn blog_with_titles(titles: &[&str]) -> BlogPostsForJson {
+    let mut blog_posts = BlogPostsForJson::default();
+    // Each a day after the one before:
+    for (days, title) in titles.iter().enumerate() {
+        blog_posts.add_post(Post {
+            title: title.to_string(),
+            content: vec!["content".to_string()],
+            tags: vec![],
+            published: true,
+            scheduled_time: Some(Local::now() - Duration::days(10 - days as i64)),
+        });
+    }
+    blog_posts
+}
+
+fn ordered_titles(blog_posts: &BlogPostsForJson) -> Vec<&str> {
+    blog_posts
+        .ordered_posts()
+        .map(|post| post.title.as_str())
+        .collect()
+}
+
+#[test]
+fn lists_newest_first_by_default() {
+    let blog_posts = blog_with_titles(&["a", "b", "c"]);
+    assert_eq!(ordered_titles(&blog_posts), vec!["c", "b", "a"]);
+}
+
+#[test]
+fn lists_oldest_first_without_reordering_the_posts() {
+    let mut blog_posts = blog_with_titles(&["a", "b", "c"]);
+    blog_posts.set_order(PostOrder::OldestFirst);
+    assert_eq!(ordered_titles(&blog_posts), vec!["a", "b", "c"]);
+    assert_eq!(blog_posts.posts()[0].title, "c");
+}
+
+#[test]
+fn reverses_any_double_ended_iterator() {
+    let reversed: Vec<usize> = PostOrder::OldestFirst.apply(0..4).collect();
+    assert_eq!(reversed, vec![3, 2, 1, 0]);
+    assert_eq!(PostOrder::OldestFirst.apply(0..4).len(), 4);
+    let back: Vec<usize> = PostOrder::OldestFirst.apply(0..4).rev().collect();
+    assert_eq!(back, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn toggling_the_order_leaves_the_posts_file_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    let mut blog_posts = blog_with_titles(&["a", "b", "c"]);
+    blog_posts.save_to_file(&filename).unwrap();
+    let before = std::fs::read(&filename).unwrap();
+
+    blog_posts.set_order(PostOrder::OldestFirst);
+    blog_posts.save_to_file(&filename).unwrap();
+    assert_eq!(std::fs::read(&filename).unwrap(), before);
+}
+
+#[test]
+fn exports_the_feed_in_the_chosen_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let rss = dir.path().join("feed.xml");
+    let mut blog_posts = blog_with_titles(&["first", "second"]);
+    blog_posts.set_order(PostOrder::OldestFirst);
+    blog::export_rss(&rss, &blog_posts, false).unwrap();
+    let feed = std::fs::read_to_string(&rss).unwrap();
+    assert!(feed.find("first").unwrap() < feed.find("second").unwrap());
+}
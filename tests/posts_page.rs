@@ -0,0 +1,38 @@
+use azifirsteframe::blog::{BlogPostsForJson, Post};
+
+fn blog_with_posts(count: usize) -> BlogPostsForJson {
+    let mut blog_posts = BlogPostsForJson::default();
+    for number in 0..count {
+        blog_posts.add_post(Post {
+            title: format!("Post {}", number),
+            content: vec!["content".to_string()],
+            tags: vec![],
+            published: true,
+            scheduled_time: None,
+        });
+    }
+    blog_posts
+}
+
+#[test]
+fn full_pages_have_size_posts() {
+    let blog_posts = blog_with_posts(45);
+    assert_eq!(blog_posts.posts_page(0, 20).len(), 20);
+    assert_eq!(blog_posts.posts_page(1, 20).len(), 20);
+}
+
+#[test]
+fn last_page_is_partial() {
+    let blog_posts = blog_with_posts(45);
+    let last = blog_posts.posts_page(2, 20);
+    assert_eq!(last.len(), 5);
+    assert_eq!(last[4].title, blog_posts.posts()[44].title);
+}
+
+#[test]
+fn out_of_range_pages_are_empty() {
+    let blog_posts = blog_with_posts(45);
+    assert!(blog_posts.posts_page(3, 20).is_empty());
+    assert!(blog_posts.posts_page(usize::MAX, 20).is_empty());
+    assert!(blog_with_posts(0).posts_page(0, 20).is_empty());
+}
@@ -0,0 +1,45 @@
+use std::ops::ControlFlow;
+
+use azifirsteframe::blog::{self, BlogError, BlogPostsForJson};
+
+#[test]
+fn cancelling_an_import_leaves_the_posts_alone() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    for name in ["a", "b", "c"] {
+        std::fs::write(dir.join(format!("{}.md", name)), "# Title\nBody").unwrap();
+    }
+    let posts_file = dir.join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    let result =
+        blog::import_dir_with_progress(dir, &mut blog_posts, &posts_file, &mut |done, _| {
+            if done == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+    assert!(matches!(result, Err(BlogError::Cancelled)));
+    assert!(blog_posts.posts().is_empty());
+    assert!(!posts_file.exists());
+}
+
+#[test]
+fn import_progress_counts_every_file() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    for name in ["a", "b"] {
+        std::fs::write(dir.join(format!("{}.txt", name)), "Body").unwrap();
+    }
+    let posts_file = dir.join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    let mut steps = vec![];
+    let count =
+        blog::import_dir_with_progress(dir, &mut blog_posts, &posts_file, &mut |done, total| {
+            steps.push((done, total));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(steps, vec![(1, 2), (2, 2)]);
+}
@@ -0,0 +1,104 @@
+use azifirsteframe::blog::{self, BlogPostsForJson, Post};
+use chrono::{Local, TimeZone};
+
+fn post(title: &str, content: &str) -> Post {
+    Post {
+        title: title.to_string(),
+        content: blog::split_on_line_breaks(content),
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    }
+}
+
+fn is_soleilfou(time: &str) -> bool {
+    let parts: Vec<&str> = time.split(':').collect();
+    let widths = [4, 2, 2, 2, 2, 2];
+    parts.len() == widths.len()
+        && parts
+            .iter()
+            .zip(widths)
+            .all(|(part, width)| part.len() == width && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[test]
+fn publishes_two_posts_to_the_env_var_file() {
+    let dir = tempfile::tempdir().unwrap();
+    // SAFETY: no other test reads or writes the environment.
+    unsafe { std::env::set_var("BLOG_POSTS_FILE", dir.path().join("posts.json")) };
+    let filename = blog::default_posts_file().unwrap();
+    let mut blog_posts = blog::load(&filename).unwrap();
+
+    blog::publish(post("First", "one"), &mut blog_posts, &filename).unwrap();
+    blog::publish(post("Second", "two\nlines"), &mut blog_posts, &filename).unwrap();
+
+    let posts = BlogPostsForJson::from_file(&filename)
+        .unwrap()
+        .posts()
+        .to_vec();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].title, "Second");
+    assert_eq!(posts[0].content, vec!["two", "lines"]);
+    assert_eq!(posts[1].title, "First");
+    for post in &posts {
+        assert!(
+            is_soleilfou(&post.woa_time),
+            "bad woa_time {}",
+            post.woa_time
+        );
+        assert!(blog::soleilfou_to_time(&post.woa_time).is_ok());
+    }
+}
+
+#[test]
+fn publishing_to_a_new_path_creates_a_valid_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("new.json");
+    assert!(!filename.exists());
+    let mut blog_posts = blog::load(&filename).unwrap();
+
+    blog::publish(post("Hello", "world"), &mut blog_posts, &filename).unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&filename).unwrap()).unwrap();
+    assert_eq!(json["posts"][0]["title"], "Hello");
+    let blog_posts = BlogPostsForJson::from_file(&filename).unwrap();
+    assert_eq!(blog_posts.posts().len(), 1);
+}
+
+#[test]
+fn a_given_time_is_written_to_the_file_as_is() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("posts.json");
+    let mut blog_posts = blog::load(&filename).unwrap();
+    let time = Local.with_ymd_and_hms(2021, 7, 4, 18, 5, 9).unwrap();
+    let post = Post {
+        scheduled_time: Some(time),
+        ..post("Backdated", "content")
+    };
+
+    blog::publish(post, &mut blog_posts, &filename).unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&filename).unwrap()).unwrap();
+    assert_eq!(json["posts"][0]["woa_time"], "2021:07:04:18:05:09");
+    let blog_posts = BlogPostsForJson::from_file(&filename).unwrap();
+    assert_eq!(blog_posts.post_time(&blog_posts.posts()[0]).unwrap(), time);
+}
+
+#[test]
+fn dry_run_reports_the_plan_without_writing() {
+    let dir = tempfile::tempdir().unwrap();
+    let filename = dir.path().join("new.json");
+
+    let blog_posts = blog::load(&filename).unwrap();
+
+    let plan =
+        blog::publish_dry_run(&post("Hello World", "content"), &blog_posts, &filename).unwrap();
+
+    assert!(plan.creates_file);
+    assert_eq!(plan.slug, "hello-world");
+    assert_eq!(plan.post_count, 1);
+    assert!(is_soleilfou(&plan.woa_time));
+    assert!(!filename.exists());
+}
@@ -0,0 +1,43 @@
+use azifirsteframe::blog::{relative_time, soleilfou_to_time};
+use chrono::Duration;
+
+fn now() -> chrono::DateTime<chrono::Local> {
+    soleilfou_to_time("2024:06:15:12:00:00").unwrap()
+}
+
+fn ago(duration: Duration) -> String {
+    relative_time(now() - duration, now())
+}
+
+#[test]
+fn moments_ago_is_just_now() {
+    assert_eq!(ago(Duration::zero()), "just now");
+    assert_eq!(ago(Duration::seconds(9)), "just now");
+}
+
+#[test]
+fn counts_in_the_largest_whole_unit() {
+    assert_eq!(ago(Duration::seconds(45)), "45 seconds ago");
+    assert_eq!(ago(Duration::minutes(1)), "1 minute ago");
+    assert_eq!(
+        ago(Duration::minutes(59) + Duration::seconds(59)),
+        "59 minutes ago"
+    );
+    assert_eq!(ago(Duration::hours(2)), "2 hours ago");
+    assert_eq!(ago(Duration::days(1)), "1 day ago");
+    assert_eq!(ago(Duration::days(6)), "6 days ago");
+    assert_eq!(ago(Duration::days(7)), "1 week ago");
+    assert_eq!(ago(Duration::days(30)), "4 weeks ago");
+}
+
+#[test]
+fn future_times_count_down() {
+    assert_eq!(ago(-Duration::hours(3)), "in 3 hours");
+    assert_eq!(ago(-Duration::days(14)), "in 2 weeks");
+}
+
+#[test]
+fn parsed_woa_times_work_too() {
+    let then = soleilfou_to_time("2024:06:15:09:30:00").unwrap();
+    assert_eq!(relative_time(then, now()), "2 hours ago");
+}
@@ -0,0 +1,84 @@
+use azifirsteframe::blog::{self, BlogError, BlogPostsForJson, FooterLink, Post};
+
+fn blog_with_titles(titles: &[&str]) -> BlogPostsForJson {
+    let mut blog_posts = BlogPostsForJson::default();
+    for title in titles {
+        blog_posts.add_post(Post {
+            title: title.to_string(),
+            content: vec!["content".to_string()],
+            tags: vec![],
+            published: true,
+            scheduled_time: None,
+        });
+    }
+    blog_posts
+}
+
+fn titles(blog_posts: &BlogPostsForJson) -> Vec<&str> {
+    blog_posts
+        .posts()
+        .iter()
+        .map(|post| post.title.as_str())
+        .collect()
+}
+
+#[test]
+fn moves_to_the_start() {
+    let mut blog_posts = blog_with_titles(&["a", "b", "c"]);
+    let before = titles(&blog_posts).join("");
+    blog_posts.move_post(2, 0).unwrap();
+    let after = titles(&blog_posts).join("");
+    assert_eq!(after, format!("{}{}", &before[2..], &before[..2]));
+}
+
+#[test]
+fn moves_to_the_end() {
+    let mut blog_posts = blog_with_titles(&["a", "b", "c"]);
+    let before = titles(&blog_posts).join("");
+    blog_posts.move_post(0, 2).unwrap();
+    let after = titles(&blog_posts).join("");
+    assert_eq!(after, format!("{}{}", &before[1..], &before[..1]));
+}
+
+#[test]
+fn moving_to_the_same_position_changes_nothing() {
+    let mut blog_posts = blog_with_titles(&["a", "b", "c"]);
+    let before = titles(&blog_posts).join("");
+    blog_posts.move_post(1, 1).unwrap();
+    assert_eq!(titles(&blog_posts).join(""), before);
+}
+
+#[test]
+fn out_of_bounds_moves_fail() {
+    let mut blog_posts = blog_with_titles(&["a", "b"]);
+    assert!(matches!(
+        blog_posts.move_post(0, 2),
+        Err(BlogError::PostNotFound(2))
+    ));
+    assert!(matches!(
+        blog_posts.move_footer_link(0, 0),
+        Err(BlogError::FooterLinkNotFound(0))
+    ));
+}
+
+#[test]
+fn moves_footer_links() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    let links = ["a", "b", "c"]
+        .iter()
+        .map(|label| FooterLink {
+            label: label.to_string(),
+            url: format!("https://example.com/{}", label),
+        })
+        .collect();
+    blog::save_footer_links(links, &mut blog_posts, &path).unwrap();
+    blog_posts.move_footer_link(0, 2).unwrap();
+    let labels: Vec<&str> = blog_posts
+        .footer_links()
+        .iter()
+        .map(|link| link.label.as_str())
+        .collect();
+    assert_eq!(labels, ["b", "c", "a"]);
+}
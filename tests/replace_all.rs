@@ -0,0 +1,39 @@
+use azifirsteframe::blog::replace_all;
+
+#[test]
+fn an_empty_find_leaves_the_text_unchanged() {
+    assert_eq!(replace_all("some text", "", "x", true), "some text");
+    assert_eq!(replace_all("some text", "", "x", false), "some text");
+}
+
+#[test]
+fn replaces_every_occurrence_matching_case() {
+    assert_eq!(
+        replace_all("cat Cat cat", "cat", "dog", true),
+        "dog Cat dog"
+    );
+}
+
+#[test]
+fn replaces_every_occurrence_ignoring_case() {
+    assert_eq!(
+        replace_all("cat Cat CAT", "cat", "dog", false),
+        "dog dog dog"
+    );
+}
+
+#[test]
+fn ignoring_case_works_past_ascii() {
+    assert_eq!(
+        replace_all("Éclair, éclair", "ÉCLAIR", "tart", false),
+        "tart, tart"
+    );
+}
+
+#[test]
+fn text_without_a_match_is_unchanged() {
+    assert_eq!(
+        replace_all("nothing here", "cat", "dog", false),
+        "nothing here"
+    );
+}
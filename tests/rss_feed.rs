@@ -0,0 +1,51 @@
+use azifirsteframe::blog::{self, BlogPostsForJson, Page};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+/// The text of the `<link>` directly inside `<channel>`, if there is one.
+fn channel_link(xml: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    let mut path: Vec<String> = vec![];
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(tag) => path.push(tag.name().as_ref().to_string()),
+            Event::End(_) => {
+                path.pop();
+            }
+            Event::Text(text) if path == ["rss", "channel", "link"] => {
+                return Some(text.to_string());
+            }
+            Event::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+fn export_with_site_url(site_url: Option<&str>) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    let page = Page {
+        title: "Blog".to_string(),
+        site_url: site_url.map(str::to_string),
+        ..blog_posts.page().clone()
+    };
+    blog::save_page(page, &mut blog_posts, &posts_file).unwrap();
+    let feed = dir.path().join("feed.xml");
+    blog::export_rss(&feed, &blog_posts, false).unwrap();
+    std::fs::read_to_string(&feed).unwrap()
+}
+
+#[test]
+fn the_channel_links_to_the_site_url() {
+    let feed = export_with_site_url(Some("https://example.com/blog"));
+    assert_eq!(
+        channel_link(&feed).as_deref(),
+        Some("https://example.com/blog")
+    );
+}
+
+#[test]
+fn no_link_is_made_up_without_a_site_url() {
+    assert_eq!(channel_link(&export_with_site_url(None)), None);
+}
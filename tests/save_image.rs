@@ -0,0 +1,35 @@
+use azifirsteframe::blog;
+
+#[test]
+fn saves_pixels_as_a_png_in_the_images_folder() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let red = [255, 0, 0, 255].repeat(4);
+
+    let reference = blog::save_image(&red, 2, 2, &posts_file).unwrap();
+    assert!(reference.starts_with("images/pasted-"));
+    assert!(reference.ends_with(".png"));
+    let saved = image::open(dir.path().join(&reference)).unwrap().to_rgba8();
+    assert_eq!(saved.dimensions(), (2, 2));
+    assert_eq!(saved.into_raw(), red);
+}
+
+#[test]
+fn numbers_a_second_paste_in_the_same_second() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let pixel = [0, 0, 0, 255];
+
+    let first = blog::save_image(&pixel, 1, 1, &posts_file).unwrap();
+    let second = blog::save_image(&pixel, 1, 1, &posts_file).unwrap();
+    assert_ne!(first, second);
+    assert!(dir.path().join(&second).exists());
+}
+
+#[test]
+fn rejects_pixels_that_dont_fill_the_image() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let result = blog::save_image(&[0, 0, 0], 1, 1, &posts_file);
+    assert!(matches!(result, Err(blog::BlogError::CouldNotSaveImage(_))));
+}
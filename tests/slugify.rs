@@ -0,0 +1,24 @@
+use azifirsteframe::blog::slugify;
+
+#[test]
+fn lowercases_and_joins_words_with_dashes() {
+    assert_eq!(slugify("Hello, World!"), "hello-world");
+    assert_eq!(slugify("  Spaced   out - title  "), "spaced-out-title");
+}
+
+#[test]
+fn keeps_unicode_letters() {
+    assert_eq!(slugify("Café Crème"), "café-crème");
+    assert_eq!(slugify("日本語 の ブログ"), "日本語-の-ブログ");
+}
+
+#[test]
+fn punctuation_only_titles_are_untitled() {
+    assert_eq!(slugify("?!... ---"), "untitled");
+}
+
+#[test]
+fn empty_titles_are_untitled() {
+    assert_eq!(slugify(""), "untitled");
+    assert_eq!(slugify("   "), "untitled");
+}
@@ -0,0 +1,49 @@
+use azifirsteframe::blog::{BlogPostsForJson, Post};
+use chrono::{Local, TimeZone};
+
+fn titles(blog_posts: &BlogPostsForJson) -> Vec<&str> {
+    blog_posts
+        .posts()
+        .iter()
+        .map(|post| post.title.as_str())
+        .collect()
+}
+
+#[test]
+fn a_shuffled_file_loads_newest_first() {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    std::fs::write(
+        &posts_file,
+        r#"{"schema_version": 1, "page": {"title": "Blog", "css": ""}, "footer_links": [],
+            "posts": [{"woa_time": "2024:03:01:00:00:00", "title": "March", "content": []},
+                      {"woa_time": "whenever", "title": "Unparsable", "content": []},
+                      {"woa_time": "2024:01:01:00:00:00", "title": "January", "content": []},
+                      {"woa_time": "2024:12:01:00:00:00", "title": "December", "content": []},
+                      {"woa_time": "2024:06:01:00:00:00", "title": "June", "content": []}]}"#,
+    )
+    .unwrap();
+    let blog_posts = BlogPostsForJson::from_file(&posts_file).unwrap();
+    assert_eq!(
+        titles(&blog_posts),
+        vec!["December", "June", "March", "January", "Unparsable"]
+    );
+}
+
+#[test]
+fn posts_added_out_of_order_are_sorted_newest_first() {
+    let mut blog_posts = BlogPostsForJson::default();
+    for (title, month) in [("May", 5), ("February", 2), ("November", 11), ("August", 8)] {
+        blog_posts.add_post(Post {
+            title: title.to_string(),
+            content: vec![],
+            tags: vec![],
+            published: true,
+            scheduled_time: Some(Local.with_ymd_and_hms(2024, month, 1, 12, 0, 0).unwrap()),
+        });
+    }
+    assert_eq!(
+        titles(&blog_posts),
+        vec!["November", "August", "May", "February"]
+    );
+}
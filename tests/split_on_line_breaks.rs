@@ -0,0 +1,22 @@
+use azifirsteframe::blog::split_on_line_breaks;
+
+#[test]
+fn windows_line_breaks_and_a_trailing_one() {
+    assert_eq!(split_on_line_breaks("a\r\nb\r\n"), vec!["a", "b"]);
+}
+
+#[test]
+fn lone_carriage_returns_break_lines() {
+    assert_eq!(split_on_line_breaks("a\rb"), vec!["a", "b"]);
+}
+
+#[test]
+fn blank_lines_are_kept() {
+    assert_eq!(split_on_line_breaks("a\n\nb"), vec!["a", "", "b"]);
+    assert_eq!(split_on_line_breaks("a\n\n"), vec!["a", ""]);
+}
+
+#[test]
+fn empty_content_has_no_lines() {
+    assert!(split_on_line_breaks("").is_empty());
+}
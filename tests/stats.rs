@@ -0,0 +1,46 @@
+use azifirsteframe::blog::{BlogPostsForJson, Post, UNKNOWN_MONTH};
+use chrono::{Local, TimeZone};
+
+fn post(title: &str, tags: &[&str], month: u32) -> Post {
+    Post {
+        title: title.to_string(),
+        content: vec!["content".to_string()],
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        published: true,
+        scheduled_time: Some(Local.with_ymd_and_hms(2024, month, 10, 12, 0, 0).unwrap()),
+    }
+}
+
+#[test]
+fn counts_posts_months_and_tags() {
+    let mut blog_posts = BlogPostsForJson::default();
+    blog_posts.add_post(post("One", &["rust", "life"], 1));
+    blog_posts.add_post(post("Two", &["rust"], 1));
+    blog_posts.add_post(post("Three", &["art"], 3));
+    let stats = blog_posts.stats();
+    assert_eq!(stats.total_posts, 3);
+    assert_eq!(stats.posts_per_month.get("2024-01"), Some(&2));
+    assert_eq!(stats.posts_per_month.get("2024-03"), Some(&1));
+    assert_eq!(
+        stats.tag_counts,
+        vec![
+            ("rust".to_string(), 2),
+            ("art".to_string(), 1),
+            ("life".to_string(), 1)
+        ]
+    );
+}
+
+#[test]
+fn buckets_unparsable_times_as_unknown() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("posts.json");
+    std::fs::write(
+        &path,
+        r#"{"schema_version":1,"page":{"title":"","css":""},"footer_links":[],"posts":[{"woa_time":"whenever","title":"Odd","content":[],"slug":"odd"}]}"#,
+    )
+    .unwrap();
+    let stats = BlogPostsForJson::from_file(&path).unwrap().stats();
+    assert_eq!(stats.total_posts, 1);
+    assert_eq!(stats.posts_per_month.get(UNKNOWN_MONTH), Some(&1));
+}
@@ -0,0 +1,27 @@
+use azifirsteframe::blog::{BlogPostsForJson, Post};
+
+fn blog_with_title(title: &str) -> BlogPostsForJson {
+    let mut blog_posts = BlogPostsForJson::default();
+    blog_posts.add_post(Post {
+        title: title.to_string(),
+        content: vec!["content".to_string()],
+        tags: vec![],
+        published: true,
+        scheduled_time: None,
+    });
+    blog_posts
+}
+
+#[test]
+fn matches_ignoring_case_and_whitespace() {
+    let blog_posts = blog_with_title("Today's Entry");
+    assert!(blog_posts.title_exists("Today's Entry"));
+    assert!(blog_posts.title_exists("  today's ENTRY "));
+}
+
+#[test]
+fn other_titles_dont_match() {
+    let blog_posts = blog_with_title("Today's Entry");
+    assert!(!blog_posts.title_exists("Today's Entry 2"));
+    assert!(!BlogPostsForJson::default().title_exists("Today's Entry"));
+}
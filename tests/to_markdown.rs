@@ -0,0 +1,25 @@
+use azifirsteframe::blog::PostForJson;
+
+fn post(title: &str, content: &[&str]) -> PostForJson {
+    PostForJson {
+        woa_time: "".to_string(),
+        title: title.to_string(),
+        content: content.iter().map(|line| line.to_string()).collect(),
+        tags: vec!["ignored".to_string()],
+        slug: "slug".to_string(),
+        published: true,
+        assets: vec![],
+        history: vec![],
+    }
+}
+
+#[test]
+fn title_becomes_a_heading_above_the_content() {
+    let post = post("Hello", &["First *line*", "", "- a list"]);
+    assert_eq!(post.to_markdown(), "# Hello\n\nFirst *line*\n\n- a list\n");
+}
+
+#[test]
+fn a_post_without_content_is_just_its_heading() {
+    assert_eq!(post("Empty", &[]).to_markdown(), "# Empty\n");
+}
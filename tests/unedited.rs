@@ -0,0 +1,68 @@
+use azifirsteframe::blog::{self, PostForJson};
+
+const DEFAULTS: (&str, &str) = ("Today's Entry", "Something happend today");
+
+#[test]
+fn a_blank_editor_is_unedited() {
+    assert!(blog::is_unedited("", "", "", DEFAULTS));
+}
+
+#[test]
+fn the_starting_title_and_content_are_unedited() {
+    assert!(blog::is_unedited(DEFAULTS.0, DEFAULTS.1, "", DEFAULTS));
+}
+
+#[test]
+fn the_editor_after_publishing_is_unedited() {
+    assert!(blog::is_unedited(DEFAULTS.0, "", "", DEFAULTS));
+    assert!(blog::is_unedited(
+        "2026-10-14 — ",
+        "",
+        "",
+        ("2026-10-14 — ", DEFAULTS.1)
+    ));
+}
+
+#[test]
+fn typing_anything_counts_as_an_edit() {
+    assert!(!blog::is_unedited(DEFAULTS.0, "A thought", "", DEFAULTS));
+    assert!(!blog::is_unedited("My title", "", "", DEFAULTS));
+    assert!(!blog::is_unedited("", DEFAULTS.1, "", DEFAULTS));
+}
+
+#[test]
+fn typing_only_tags_counts_as_an_edit() {
+    assert!(!blog::is_unedited(DEFAULTS.0, "", "rust", DEFAULTS));
+    assert!(blog::is_unedited(DEFAULTS.0, "", "  ", DEFAULTS));
+}
+
+fn saved_post() -> PostForJson {
+    PostForJson {
+        woa_time: "2024:01:01:00:00:00".to_string(),
+        title: "Title".to_string(),
+        content: vec!["one".to_string(), "two".to_string()],
+        tags: vec!["rust".to_string(), "life".to_string()],
+        slug: "title".to_string(),
+        published: true,
+        assets: vec![],
+        history: vec![],
+    }
+}
+
+#[test]
+fn a_post_as_it_was_loaded_is_not_edited() {
+    assert!(!saved_post().is_edited("Title", "one\ntwo", "rust, life", true));
+}
+
+#[test]
+fn editing_only_the_tags_of_a_post_counts() {
+    assert!(saved_post().is_edited("Title", "one\ntwo", "rust", true));
+    assert!(saved_post().is_edited("Title", "one\ntwo", "rust, life, art", true));
+}
+
+#[test]
+fn editing_the_title_content_or_published_state_counts() {
+    assert!(saved_post().is_edited("Other", "one\ntwo", "rust, life", true));
+    assert!(saved_post().is_edited("Title", "one", "rust, life", true));
+    assert!(saved_post().is_edited("Title", "one\ntwo", "rust, life", false));
+}
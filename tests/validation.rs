@@ -0,0 +1,38 @@
+use azifirsteframe::blog::{self, BlogPostsForJson, FooterLink, ValidationWarning};
+
+fn link(url: &str) -> FooterLink {
+    FooterLink {
+        label: "Link".to_string(),
+        url: url.to_string(),
+    }
+}
+
+fn warnings_for(urls: &[&str]) -> Vec<ValidationWarning> {
+    let dir = tempfile::tempdir().unwrap();
+    let posts_file = dir.path().join("posts.json");
+    let mut blog_posts = BlogPostsForJson::default();
+    let links = urls.iter().map(|url| link(url)).collect();
+    blog::save_footer_links(links, &mut blog_posts, &posts_file).unwrap();
+    blog_posts.validate()
+}
+
+#[test]
+fn absolute_http_links_are_fine() {
+    assert!(warnings_for(&["https://example.com", "http://example.com/about"]).is_empty());
+}
+
+#[test]
+fn other_schemes_are_flagged_by_index() {
+    let warnings = warnings_for(&["https://example.com", "mailto:me@example.com"]);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].index, 1);
+    assert!(warnings[0].message.contains("mailto:me@example.com"));
+}
+
+#[test]
+fn relative_and_malformed_urls_are_flagged() {
+    assert!(link("/about").url_warning().is_some());
+    assert!(link("http://exa mple.com").url_warning().is_some());
+    assert!(link("").url_warning().is_some());
+    assert_eq!(link("https://example.com").url_warning(), None);
+}